@@ -0,0 +1,68 @@
+use iced::{
+    widget::{button, column, container, text},
+    Border, Color, Element, Length, Theme,
+};
+use iced_anim::{animation_builder::AnimationBuilder, transition::Easing};
+
+#[derive(Debug, Clone)]
+enum Message {
+    Toggle,
+}
+
+#[derive(Default)]
+struct State {
+    is_toggled: bool,
+}
+
+impl State {
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Toggle => self.is_toggled = !self.is_toggled,
+        }
+    }
+
+    /// The background color for the current state, picked from the theme's palette.
+    fn background(&self, theme: &Theme) -> Color {
+        let palette = theme.palette();
+        if self.is_toggled {
+            palette.success
+        } else {
+            palette.primary
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let toggle = button(text("Toggle background")).on_press(Message::Toggle);
+
+        // `Color` implements `Animate`, so the builder can animate it directly.
+        let background = AnimationBuilder::new(self.background(&self.theme()), |color| {
+            container(text("Animated background").color(Color::WHITE))
+                .style(move |_| container::Style {
+                    background: Some(color.into()),
+                    border: Border::default().rounded(6),
+                    ..Default::default()
+                })
+                .center_x(Length::Fixed(200.0))
+                .center_y(Length::Fixed(100.0))
+                .into()
+        })
+        .animation(Easing::EASE_IN_OUT);
+
+        column![toggle, background]
+            .spacing(8)
+            .padding(8)
+            .width(Length::Shrink)
+            .into()
+    }
+
+    fn theme(&self) -> Theme {
+        Theme::Dark
+    }
+}
+
+pub fn main() -> iced::Result {
+    iced::application(State::default, State::update, State::view)
+        .title("Animated Background")
+        .theme(State::theme)
+        .run()
+}
@@ -117,22 +117,17 @@ impl canvas::Program<Message> for AppState {
     ) -> Option<canvas::Action<Message>> {
         match event {
             canvas::Event::Mouse(mouse::Event::CursorMoved { position }) => {
-                let Some(index) = self
+                let index = self
                     .shapes
                     .iter()
-                    .position(|shape| shape.contains(*position))
-                else {
-                    return None;
-                };
+                    .position(|shape| shape.contains(*position))?;
 
                 // Don't update the selection if it hasn't changed
                 if index == self.hovered_index {
                     return None;
                 }
 
-                Some(Message::SelectIndex(index))
-                    .map(canvas::Action::publish)
-                    .map(canvas::Action::and_capture)
+                Some(canvas::Action::publish(Message::SelectIndex(index)).and_capture())
             }
             _ => None,
         }
@@ -0,0 +1,80 @@
+use iced::{
+    widget::{button, center, mouse_area, text},
+    Border, Element, Shadow, Theme, Vector,
+};
+use iced_anim::{animation_builder::AnimationBuilder, transition::Easing};
+
+#[derive(Debug, Clone)]
+enum Message {
+    Hover(bool),
+    Pressed,
+}
+
+#[derive(Default)]
+struct State {
+    is_hovered: bool,
+    presses: usize,
+}
+
+impl State {
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Hover(is_hovered) => self.is_hovered = is_hovered,
+            Message::Pressed => self.presses += 1,
+        }
+    }
+
+    /// The style of the button for the current hover state.
+    fn style(&self, theme: &Theme) -> button::Style {
+        let palette = theme.extended_palette();
+        let pair = if self.is_hovered {
+            palette.primary.strong
+        } else {
+            palette.primary.base
+        };
+
+        button::Style {
+            background: Some(pair.color.into()),
+            text_color: pair.text,
+            border: Border::default().rounded(if self.is_hovered { 16 } else { 4 }),
+            shadow: Shadow {
+                color: palette.background.base.text.scale_alpha(0.4),
+                offset: Vector::new(0.0, if self.is_hovered { 6.0 } else { 1.0 }),
+                blur_radius: if self.is_hovered { 12.0 } else { 2.0 },
+            },
+            ..Default::default()
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let label = format!("Pressed {} times", self.presses);
+
+        // `button::Style` implements `Animate`, so the whole style animates between states.
+        let button = AnimationBuilder::new(self.style(&self.theme()), move |style| {
+            button(text(label.clone()))
+                .padding([12, 24])
+                .style(move |_, _| style)
+                .on_press(Message::Pressed)
+                .into()
+        })
+        .animation(Easing::EASE_OUT);
+
+        center(
+            mouse_area(button)
+                .on_enter(Message::Hover(true))
+                .on_exit(Message::Hover(false)),
+        )
+        .into()
+    }
+
+    fn theme(&self) -> Theme {
+        Theme::Light
+    }
+}
+
+pub fn main() -> iced::Result {
+    iced::application(State::default, State::update, State::view)
+        .title("Animated Hover")
+        .theme(State::theme)
+        .run()
+}
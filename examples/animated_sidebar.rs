@@ -0,0 +1,61 @@
+use iced::{
+    widget::{button, column, container, row, text},
+    Element, Length,
+};
+use iced_anim::{length::AnimatedLength, transition::Easing, AnimationBuilder};
+
+#[derive(Debug, Clone)]
+enum Message {
+    ToggleSidebar,
+}
+
+#[derive(Default)]
+struct State {
+    is_collapsed: bool,
+}
+
+impl State {
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::ToggleSidebar => self.is_collapsed = !self.is_collapsed,
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let content = column![
+            text("Content"),
+            button(text("Toggle sidebar")).on_press(Message::ToggleSidebar),
+        ]
+        .spacing(8)
+        .width(200);
+
+        let width = if self.is_collapsed {
+            AnimatedLength::fixed(0.0)
+        } else {
+            AnimatedLength::fill()
+        };
+
+        // The sidebar fills the remaining space, so the available width is measured during
+        // layout to give the animation a numeric target.
+        let sidebar = AnimationBuilder::new(width, |width| {
+            container(text("Sidebar"))
+                .style(container::rounded_box)
+                .padding(8)
+                .width(width.length())
+                .height(Length::Fill)
+                .clip(true)
+                .into()
+        })
+        .resolve_target(|width, available| width.resolve(available.width))
+        .animation(Easing::EASE_IN_OUT)
+        .animates_layout(true);
+
+        row![content, sidebar].spacing(8).padding(8).into()
+    }
+}
+
+pub fn main() -> iced::Result {
+    iced::application(State::default, State::update, State::view)
+        .title("Animated Sidebar")
+        .run()
+}
@@ -0,0 +1,83 @@
+use iced::{
+    widget::{button, column, container, row, space, text},
+    Border, Element, Length,
+};
+use iced_anim::{animation_builder::AnimationBuilder, transition::Easing};
+use std::time::Duration;
+
+#[derive(Debug, Clone)]
+enum Message {
+    AdjustSize(f32),
+    /// The size of the box while it's animating.
+    Resized(f32),
+}
+
+struct State {
+    size: f32,
+    /// The current animated size of the box, which the label follows.
+    animated_size: f32,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self {
+            size: 50.0,
+            animated_size: 50.0,
+        }
+    }
+}
+
+impl State {
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::AdjustSize(dx) => {
+                self.size = (self.size + dx).max(0.0);
+            }
+            Message::Resized(size) => self.animated_size = size,
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let buttons = row![
+            button(text("-50")).on_press(Message::AdjustSize(-50.0)),
+            button(text("+50")).on_press(Message::AdjustSize(50.0)),
+        ]
+        .spacing(8);
+
+        let animated_box = AnimationBuilder::new(self.size, |size| {
+            container(text(size as isize))
+                .style(move |theme: &iced::Theme| iced::widget::container::Style {
+                    border: Border {
+                        color: theme.extended_palette().secondary.strong.color,
+                        width: 1.0,
+                        radius: 6.0.into(),
+                    },
+                    background: Some(theme.extended_palette().secondary.weak.color.into()),
+                    ..Default::default()
+                })
+                .center(size)
+                .into()
+        })
+        .animates_layout(true)
+        .animation(Easing::EASE.with_duration(Duration::from_millis(800)))
+        .on_interpolate(Message::Resized);
+
+        // The label sits just below the middle of the box as it grows and shrinks.
+        let label = column![
+            space().height(self.animated_size / 2.0),
+            text(format!("{:.0}px", self.animated_size)),
+        ];
+
+        column![buttons, row![animated_box, label].spacing(8)]
+            .spacing(8)
+            .padding(8)
+            .width(Length::Shrink)
+            .into()
+    }
+}
+
+pub fn main() -> iced::Result {
+    iced::application(State::default, State::update, State::view)
+        .title("Tracking Label")
+        .run()
+}
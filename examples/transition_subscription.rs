@@ -0,0 +1,68 @@
+//! An example of driving a `Transition` stored in the app state with its own subscription,
+//! which only listens for frames while the transition is animating.
+use iced::{
+    widget::{button, column, container, space},
+    Border, Element, Length, Subscription, Theme,
+};
+use iced_anim::{transition::Easing, Event, Transition};
+
+#[derive(Debug, Clone)]
+enum Message {
+    Toggle,
+    Animate(Event<f32>),
+}
+
+struct State {
+    width: Transition<f32>,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self {
+            width: Transition::new(50.0).with_easing(Easing::EASE_IN_OUT),
+        }
+    }
+}
+
+impl State {
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Toggle => {
+                let target = if *self.width.target() > 50.0 {
+                    50.0
+                } else {
+                    300.0
+                };
+                self.width.set_target(target);
+            }
+            Message::Animate(event) => self.width.update(event),
+        }
+    }
+
+    fn subscription(&self) -> Subscription<Message> {
+        self.width.subscription().map(Message::Animate)
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let bar = container(space().width(Length::Fill).height(Length::Fill))
+            .width(*self.width.value())
+            .height(40)
+            .style(|theme: &Theme| container::Style {
+                background: Some(theme.palette().primary.into()),
+                border: Border::default().rounded(6),
+                ..Default::default()
+            });
+
+        column![button("Toggle").on_press(Message::Toggle), bar]
+            .spacing(8)
+            .padding(8)
+            .into()
+    }
+}
+
+pub fn main() -> iced::Result {
+    iced::application(State::default, State::update, State::view)
+        .subscription(State::subscription)
+        .title("Transition Subscription")
+        .run()
+}
@@ -5,7 +5,7 @@
 //!
 //! You can implement this trait for custom types using the "derive" feature.
 use iced_core::{theme::palette, Theme};
-use std::sync::Arc;
+use std::{borrow::Cow, rc::Rc, sync::Arc, time::Duration};
 
 /// A trait for types that can be animated on a per-property basis.
 ///
@@ -41,8 +41,47 @@ pub trait Animate: Clone + PartialEq {
     /// The `start` value is the initial value, the `end` value is the target value, and `progress`
     /// is a value between 0.0 and 1.0 representing the interpolation progress.
     fn lerp(&mut self, start: &Self, end: &Self, progress: f32);
+
+    /// Whether this value can be smoothly animated towards the `end` value.
+    ///
+    /// Some values can't always be animated between, e.g. gradients with different stops. Those
+    /// values still animate without panicking, but jump to parts of the `end` value instead.
+    /// This is used to report those cases with [`Transition::try_set_target`]. Defaults to
+    /// `true`.
+    ///
+    /// [`Transition::try_set_target`]: crate::Transition::try_set_target
+    fn can_animate_to(&self, end: &Self) -> bool {
+        let _ = end;
+        true
+    }
+}
+
+/// An error that occurs when a value can't be animated towards a target.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnimateError {
+    /// The target has NaN or infinite components, e.g. from dividing by zero.
+    NonFinite,
+    /// The target can't be smoothly animated towards from the current value.
+    /// See [`Animate::can_animate_to`].
+    Incompatible,
+}
+
+impl std::fmt::Display for AnimateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AnimateError::NonFinite => write!(f, "the target has non-finite components"),
+            AnimateError::Incompatible => {
+                write!(
+                    f,
+                    "the target can't be animated towards from the current value"
+                )
+            }
+        }
+    }
 }
 
+impl std::error::Error for AnimateError {}
+
 impl Animate for f32 {
     fn components() -> usize {
         1
@@ -61,6 +100,88 @@ impl Animate for f32 {
     }
 }
 
+/// Animates the unicode scalar value of a `char`, e.g. `'0'` to `'9'` steps through each digit.
+///
+/// Values are rounded to the nearest codepoint, and the surrogate range which doesn't contain
+/// valid `char`s is skipped by jumping to the next valid codepoint in the direction of motion.
+/// A `char` can't hold a position between codepoints, so a [`Spring`](crate::Spring) carries
+/// over movements that are too small to reach the next codepoint until they add up.
+impl Animate for char {
+    fn components() -> usize {
+        1
+    }
+
+    fn update(&mut self, components: &mut impl Iterator<Item = f32>) {
+        let delta = components.next().unwrap();
+        *self = char_from_scalar(*self as u32 as f32 + delta, delta >= 0.0);
+    }
+
+    fn distance_to(&self, end: &Self) -> Vec<f32> {
+        vec![*self as u32 as f32 - *end as u32 as f32]
+    }
+
+    fn lerp(&mut self, start: &Self, end: &Self, progress: f32) {
+        let start = *start as u32 as f32;
+        let end = *end as u32 as f32;
+        *self = char_from_scalar(start + (end - start) * progress, end >= start);
+    }
+}
+
+/// Converts a scalar value to the nearest valid `char`, skipping over the surrogate range
+/// upwards if `ascending` is `true` or downwards otherwise.
+fn char_from_scalar(value: f32, ascending: bool) -> char {
+    let value = (value.round().max(0.0) as u32).min(char::MAX as u32);
+    let value = match value {
+        0xD800..=0xDFFF if ascending => 0xE000,
+        0xD800..=0xDFFF => 0xD7FF,
+        _ => value,
+    };
+    char::from_u32(value).unwrap_or(char::REPLACEMENT_CHARACTER)
+}
+
+/// Animates a `Duration` in seconds. Durations can't be negative, so they stop at zero.
+impl Animate for Duration {
+    fn components() -> usize {
+        1
+    }
+
+    fn update(&mut self, components: &mut impl Iterator<Item = f32>) {
+        let seconds = self.as_secs_f32() + components.next().unwrap();
+        *self = Duration::from_secs_f32(seconds.max(0.0));
+    }
+
+    fn distance_to(&self, end: &Self) -> Vec<f32> {
+        vec![self.as_secs_f32() - end.as_secs_f32()]
+    }
+
+    fn lerp(&mut self, start: &Self, end: &Self, progress: f32) {
+        let mut seconds = start.as_secs_f32();
+        seconds.lerp(&start.as_secs_f32(), &end.as_secs_f32(), progress);
+        *self = Duration::from_secs_f32(seconds.max(0.0));
+    }
+}
+
+/// Animates the inner `f32` of `Pixels`, which can't be negative.
+impl Animate for iced_core::Pixels {
+    fn components() -> usize {
+        f32::components()
+    }
+
+    fn update(&mut self, components: &mut impl Iterator<Item = f32>) {
+        self.0.update(components);
+        self.0 = self.0.max(0.0);
+    }
+
+    fn distance_to(&self, end: &Self) -> Vec<f32> {
+        self.0.distance_to(&end.0)
+    }
+
+    fn lerp(&mut self, start: &Self, end: &Self, progress: f32) {
+        self.0.lerp(&start.0, &end.0, progress);
+        self.0 = self.0.max(0.0);
+    }
+}
+
 impl Animate for iced_core::Point<f32> {
     fn components() -> usize {
         2
@@ -94,13 +215,14 @@ impl Animate for iced_core::Color {
     }
 
     fn distance_to(&self, end: &Self) -> Vec<f32> {
-        [
-            self.r.distance_to(&end.r),
-            self.g.distance_to(&end.g),
-            self.b.distance_to(&end.b),
-            self.a.distance_to(&end.a),
+        // Channels are clamped when updating, so only measure the distance that can be covered.
+        let distance = |start: f32, end: f32| start.clamp(0.0, 1.0) - end.clamp(0.0, 1.0);
+        vec![
+            distance(self.r, end.r),
+            distance(self.g, end.g),
+            distance(self.b, end.b),
+            distance(self.a, end.a),
         ]
-        .concat()
     }
 
     fn lerp(&mut self, start: &Self, end: &Self, progress: f32) {
@@ -108,6 +230,12 @@ impl Animate for iced_core::Color {
         self.g.lerp(&start.g, &end.g, progress);
         self.b.lerp(&start.b, &end.b, progress);
         self.a.lerp(&start.a, &end.a, progress);
+
+        // Curves that overshoot shouldn't produce channels outside of the valid range.
+        self.r = self.r.clamp(0.0, 1.0);
+        self.g = self.g.clamp(0.0, 1.0);
+        self.b = self.b.clamp(0.0, 1.0);
+        self.a = self.a.clamp(0.0, 1.0);
     }
 }
 
@@ -148,6 +276,8 @@ impl Animate for iced_core::theme::Palette {
     }
 }
 
+/// Animates between themes by interpolating their palettes, e.g. to smoothly switch between
+/// light and dark mode. The theme becomes a [`Theme::Custom`] while it's animating.
 impl Animate for Theme {
     fn components() -> usize {
         iced_core::theme::Palette::components() + iced_core::theme::palette::Extended::components()
@@ -471,8 +601,122 @@ where
             }
         }
     }
+
+    fn can_animate_to(&self, end: &Self) -> bool {
+        match (self, end) {
+            (Some(start), Some(end)) => start.can_animate_to(end),
+            (None, None) => true,
+            _ => false,
+        }
+    }
 }
 
+/// Animates the boxed value, e.g. to keep large animated state on the heap.
+impl<T> Animate for Box<T>
+where
+    T: Animate,
+{
+    fn components() -> usize {
+        T::components()
+    }
+
+    fn update(&mut self, components: &mut impl Iterator<Item = f32>) {
+        self.as_mut().update(components);
+    }
+
+    fn distance_to(&self, end: &Self) -> Vec<f32> {
+        self.as_ref().distance_to(end)
+    }
+
+    fn lerp(&mut self, start: &Self, end: &Self, progress: f32) {
+        self.as_mut().lerp(start, end, progress);
+    }
+
+    fn can_animate_to(&self, end: &Self) -> bool {
+        self.as_ref().can_animate_to(end)
+    }
+}
+
+/// Animates the shared value, cloning it on write if it's shared with other owners.
+impl<T> Animate for Arc<T>
+where
+    T: Animate,
+{
+    fn components() -> usize {
+        T::components()
+    }
+
+    fn update(&mut self, components: &mut impl Iterator<Item = f32>) {
+        Arc::make_mut(self).update(components);
+    }
+
+    fn distance_to(&self, end: &Self) -> Vec<f32> {
+        self.as_ref().distance_to(end)
+    }
+
+    fn lerp(&mut self, start: &Self, end: &Self, progress: f32) {
+        Arc::make_mut(self).lerp(start, end, progress);
+    }
+
+    fn can_animate_to(&self, end: &Self) -> bool {
+        self.as_ref().can_animate_to(end)
+    }
+}
+
+/// Animates the shared value, cloning it on write if it's shared with other owners.
+impl<T> Animate for Rc<T>
+where
+    T: Animate,
+{
+    fn components() -> usize {
+        T::components()
+    }
+
+    fn update(&mut self, components: &mut impl Iterator<Item = f32>) {
+        Rc::make_mut(self).update(components);
+    }
+
+    fn distance_to(&self, end: &Self) -> Vec<f32> {
+        self.as_ref().distance_to(end)
+    }
+
+    fn lerp(&mut self, start: &Self, end: &Self, progress: f32) {
+        Rc::make_mut(self).lerp(start, end, progress);
+    }
+
+    fn can_animate_to(&self, end: &Self) -> bool {
+        self.as_ref().can_animate_to(end)
+    }
+}
+
+/// Animates the value, cloning it on write if it's borrowed.
+impl<T> Animate for Cow<'static, T>
+where
+    T: Animate,
+{
+    fn components() -> usize {
+        T::components()
+    }
+
+    fn update(&mut self, components: &mut impl Iterator<Item = f32>) {
+        self.to_mut().update(components);
+    }
+
+    fn distance_to(&self, end: &Self) -> Vec<f32> {
+        self.as_ref().distance_to(end)
+    }
+
+    fn lerp(&mut self, start: &Self, end: &Self, progress: f32) {
+        self.to_mut().lerp(start, end, progress);
+    }
+
+    fn can_animate_to(&self, end: &Self) -> bool {
+        self.as_ref().can_animate_to(end)
+    }
+}
+
+/// Animates each corner of the radius separately, so uniform radii can animate to and from
+/// asymmetric ones without losing any corners.
 impl Animate for iced_core::border::Radius {
     fn components() -> usize {
         4
@@ -662,6 +906,42 @@ impl Animate for iced_core::Radians {
     }
 }
 
+/// Animates the angle of a `Rotation`, keeping the `Floating` or `Solid` variant of the starting
+/// rotation until the animation settles at the target.
+///
+/// The variant can't be interpolated, so rotations with different variants can't be smoothly
+/// animated between, as reported by [`Animate::can_animate_to`].
+impl Animate for iced_core::Rotation {
+    fn components() -> usize {
+        iced_core::Radians::components()
+    }
+
+    fn distance_to(&self, end: &Self) -> Vec<f32> {
+        self.radians().distance_to(&end.radians())
+    }
+
+    fn update(&mut self, components: &mut impl Iterator<Item = f32>) {
+        self.radians_mut().update(components);
+    }
+
+    fn lerp(&mut self, start: &Self, end: &Self, progress: f32) {
+        let mut radians = start.radians();
+        radians.lerp(&start.radians(), &end.radians(), progress);
+
+        // Updates can't see the target's variant, so only switch to it at the end.
+        let variant = if progress >= 1.0 { end } else { start };
+        *self = match variant {
+            iced_core::Rotation::Floating(_) => iced_core::Rotation::Floating(radians),
+            iced_core::Rotation::Solid(_) => iced_core::Rotation::Solid(radians),
+        };
+    }
+
+    fn can_animate_to(&self, end: &Self) -> bool {
+        std::mem::discriminant(self) == std::mem::discriminant(end)
+    }
+}
+
+/// Animates the offset and color of a gradient stop. Offsets stay in the range [0.0, 1.0].
 impl Animate for iced_core::gradient::ColorStop {
     fn components() -> usize {
         f32::components() + iced_core::Color::components()
@@ -677,15 +957,42 @@ impl Animate for iced_core::gradient::ColorStop {
 
     fn update(&mut self, components: &mut impl Iterator<Item = f32>) {
         self.offset.update(components);
+        self.offset = self.offset.clamp(0.0, 1.0);
         self.color.update(components);
     }
 
     fn lerp(&mut self, start: &Self, end: &Self, progress: f32) {
         self.offset.lerp(&start.offset, &end.offset, progress);
+        self.offset = self.offset.clamp(0.0, 1.0);
         self.color.lerp(&start.color, &end.color, progress);
     }
 }
 
+/// Animates the color of a highlight format, e.g. to cross-fade between highlights.
+///
+/// Fonts can't be animated, so the font snaps to the target's font once the animation settles.
+impl Animate for iced_core::text::highlighter::Format<iced_core::Font> {
+    fn components() -> usize {
+        Option::<iced_core::Color>::components()
+    }
+
+    fn distance_to(&self, end: &Self) -> Vec<f32> {
+        self.color.distance_to(&end.color)
+    }
+
+    fn update(&mut self, components: &mut impl Iterator<Item = f32>) {
+        self.color.update(components);
+    }
+
+    fn lerp(&mut self, start: &Self, end: &Self, progress: f32) {
+        self.color.lerp(&start.color, &end.color, progress);
+    }
+
+    fn can_animate_to(&self, end: &Self) -> bool {
+        self.color.can_animate_to(&end.color)
+    }
+}
+
 impl<T, const N: usize> Animate for [T; N]
 where
     T: Animate,
@@ -746,6 +1053,14 @@ impl Animate for iced_core::gradient::Linear {
             stop.lerp(start, end, progress);
         }
     }
+
+    fn can_animate_to(&self, end: &Self) -> bool {
+        // Stops that are only present in one of the gradients can't be animated.
+        self.stops
+            .iter()
+            .zip(&end.stops)
+            .all(|(start, end)| start.is_some() == end.is_some())
+    }
 }
 
 impl Animate for iced_core::Gradient {
@@ -778,6 +1093,14 @@ impl Animate for iced_core::Gradient {
             }
         }
     }
+
+    fn can_animate_to(&self, end: &Self) -> bool {
+        match (self, end) {
+            (iced_core::Gradient::Linear(start), iced_core::Gradient::Linear(end)) => {
+                start.can_animate_to(end)
+            }
+        }
+    }
 }
 
 impl Animate for iced_core::Background {
@@ -836,6 +1159,16 @@ impl Animate for iced_core::Background {
             _ => {}
         }
     }
+
+    fn can_animate_to(&self, end: &Self) -> bool {
+        match (self, end) {
+            (iced_core::Background::Color(_), iced_core::Background::Color(_)) => true,
+            (iced_core::Background::Gradient(start), iced_core::Background::Gradient(end)) => {
+                start.can_animate_to(end)
+            }
+            _ => false,
+        }
+    }
 }
 
 #[cfg(feature = "widgets")]
@@ -874,6 +1207,84 @@ impl Animate for iced_widget::button::Style {
     }
 }
 
+#[cfg(feature = "widgets")]
+impl Animate for iced_widget::container::Style {
+    fn components() -> usize {
+        Option::<iced_core::Color>::components()
+            + Option::<iced_core::Background>::components()
+            + iced_core::Border::components()
+            + iced_core::Shadow::components()
+    }
+
+    fn distance_to(&self, end: &Self) -> Vec<f32> {
+        [
+            self.text_color.distance_to(&end.text_color),
+            self.background.distance_to(&end.background),
+            self.border.distance_to(&end.border),
+            self.shadow.distance_to(&end.shadow),
+        ]
+        .concat()
+    }
+
+    fn update(&mut self, components: &mut impl Iterator<Item = f32>) {
+        self.text_color.update(components);
+        self.background.update(components);
+        self.border.update(components);
+        self.shadow.update(components);
+    }
+
+    fn lerp(&mut self, start: &Self, end: &Self, progress: f32) {
+        self.text_color
+            .lerp(&start.text_color, &end.text_color, progress);
+        self.background
+            .lerp(&start.background, &end.background, progress);
+        self.border.lerp(&start.border, &end.border, progress);
+        self.shadow.lerp(&start.shadow, &end.shadow, progress);
+    }
+}
+
+#[cfg(feature = "widgets")]
+impl Animate for iced_widget::text_input::Style {
+    fn components() -> usize {
+        iced_core::Background::components()
+            + iced_core::Border::components()
+            + 4 * iced_core::Color::components()
+    }
+
+    fn distance_to(&self, end: &Self) -> Vec<f32> {
+        [
+            self.background.distance_to(&end.background),
+            self.border.distance_to(&end.border),
+            self.icon.distance_to(&end.icon),
+            self.placeholder.distance_to(&end.placeholder),
+            self.value.distance_to(&end.value),
+            self.selection.distance_to(&end.selection),
+        ]
+        .concat()
+    }
+
+    fn update(&mut self, components: &mut impl Iterator<Item = f32>) {
+        self.background.update(components);
+        self.border.update(components);
+        self.icon.update(components);
+        self.placeholder.update(components);
+        self.value.update(components);
+        self.selection.update(components);
+    }
+
+    fn lerp(&mut self, start: &Self, end: &Self, progress: f32) {
+        self.background
+            .lerp(&start.background, &end.background, progress);
+        self.border.lerp(&start.border, &end.border, progress);
+        self.icon.lerp(&start.icon, &end.icon, progress);
+        self.placeholder
+            .lerp(&start.placeholder, &end.placeholder, progress);
+        self.value.lerp(&start.value, &end.value, progress);
+        self.selection
+            .lerp(&start.selection, &end.selection, progress);
+    }
+}
+
 #[cfg(feature = "widgets")]
 impl Animate for iced_widget::svg::Style {
     fn components() -> usize {
@@ -985,6 +1396,83 @@ where
     }
 }
 
+/// Whether every component of the `value` is finite, i.e. not NaN or infinite.
+///
+/// A finite value is always zero distance from itself, while NaN or infinite components give
+/// NaN distances.
+pub(crate) fn is_finite<T: Animate>(value: &T) -> bool {
+    value.distance_to(value).iter().all(|d| d.is_finite())
+}
+
+/// Checks that a type's [`Animate`] implementation is consistent with itself, panicking with
+/// a description of the problem if it isn't.
+///
+/// This verifies that [`Animate::distance_to`] returns [`Animate::components`] items and that
+/// [`Animate::update`] consumes exactly that many, which are easy to get out of sync when
+/// implementing [`Animate`] by hand and cause updates to apply to the wrong properties.
+/// Call it from a test with two different values of your type:
+///
+/// ```rust
+/// # use iced_anim::Animate;
+/// #[derive(Clone, PartialEq)]
+/// struct Position {
+///     x: f32,
+///     y: f32,
+/// }
+///
+/// impl Animate for Position {
+///     fn components() -> usize {
+///         2
+///     }
+///
+///     fn update(&mut self, components: &mut impl Iterator<Item = f32>) {
+///         self.x.update(components);
+///         self.y.update(components);
+///     }
+///
+///     fn distance_to(&self, end: &Self) -> Vec<f32> {
+///         [self.x.distance_to(&end.x), self.y.distance_to(&end.y)].concat()
+///     }
+///
+///     fn lerp(&mut self, start: &Self, end: &Self, progress: f32) {
+///         self.x.lerp(&start.x, &end.x, progress);
+///         self.y.lerp(&start.y, &end.y, progress);
+///     }
+/// }
+///
+/// iced_anim::animate::assert_consistent(
+///     &Position { x: 0.0, y: 0.0 },
+///     &Position { x: 10.0, y: 5.0 },
+/// );
+/// ```
+pub fn assert_consistent<T: Animate>(start: &T, end: &T) {
+    let components = T::components();
+
+    for (from, to) in [(start, end), (end, start)] {
+        let distance = from.distance_to(to);
+        assert_eq!(
+            distance.len(),
+            components,
+            "`distance_to` returned {} components, but `components` is {components}",
+            distance.len(),
+        );
+
+        // Pad the distance so that updates consuming too many components can be detected.
+        let mut consumed = 0;
+        let mut iter = to
+            .distance_to(from)
+            .into_iter()
+            .chain(std::iter::repeat(0.0))
+            .inspect(|_| consumed += 1);
+        from.clone().update(&mut iter);
+        drop(iter);
+        assert_eq!(
+            consumed, components,
+            "`update` consumed {consumed} components, but `components` is {components}",
+        );
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -994,6 +1482,280 @@ mod tests {
         assert_eq!(f32::components(), 1);
     }
 
+    /// Animating between digits should step through each intermediate digit.
+    #[test]
+    fn char_lerp_steps_through_digits() {
+        let mut value = '0';
+        let mut visited = Vec::new();
+        for step in 0..=90 {
+            value.lerp(&'0', &'9', step as f32 / 90.0);
+            assert!(value.is_ascii_digit());
+            if visited.last() != Some(&value) {
+                visited.push(value);
+            }
+        }
+        assert_eq!(visited, ('0'..='9').collect::<Vec<_>>());
+    }
+
+    /// Springs should step through the digits with small updates and settle on the target.
+    #[test]
+    fn char_spring() {
+        use crate::{Motion, Spring};
+        use std::time::Instant;
+
+        for motion in [Motion::SMOOTH, Motion::SNAPPY, Motion::BOUNCY] {
+            let mut spring = Spring::new('0').to('9').with_motion(motion);
+            let mut now = Instant::now();
+            for _ in 0..300 {
+                now += Duration::from_millis(16);
+                spring.tick(now);
+            }
+            assert_eq!(*spring.value(), '9');
+            assert!(!spring.has_energy());
+        }
+    }
+
+    /// Animating across the surrogate range should never produce an invalid `char`.
+    #[test]
+    fn char_skips_surrogates() {
+        let mut value = '\u{D7FF}';
+        value.lerp(&'\u{D7FF}', &'\u{E000}', 0.5);
+        assert!(value == '\u{D7FF}' || value == '\u{E000}');
+
+        let mut value = '\u{D7FF}';
+        value.update(&mut [1.0].into_iter());
+        assert_eq!(value, '\u{E000}');
+        value.update(&mut [-1.0].into_iter());
+        assert_eq!(value, '\u{D7FF}');
+        assert_eq!('9'.distance_to(&'0'), vec![9.0]);
+    }
+
+    /// Durations should animate in seconds without going negative.
+    #[test]
+    fn duration() {
+        let start = Duration::ZERO;
+        let end = Duration::from_millis(1000);
+        let mut value = start;
+
+        value.lerp(&start, &end, 0.5);
+        assert_eq!(value, Duration::from_millis(500));
+        assert_eq!(start.distance_to(&end), vec![-1.0]);
+
+        // Overshooting below zero should stop at zero.
+        value.lerp(&start, &end, -0.2);
+        assert_eq!(value, Duration::ZERO);
+        value.update(&mut [-1.0].into_iter());
+        assert_eq!(value, Duration::ZERO);
+    }
+
+    /// Colors should stay in range and settle even when overshooting or targeting channels
+    /// outside of the valid range.
+    #[test]
+    fn color_clamping() {
+        use crate::{overshoot::Overshoot, Easing, Spring, Transition};
+        use iced_core::Color;
+        use std::time::Instant;
+
+        let mut transition = Transition::new(Overshoot::new(Color::from_rgb(0.5, 0.5, 0.5)))
+            .to(Overshoot::new(Color::from_rgb(0.99, 0.5, 0.5)))
+            .with_easing(Easing::LINEAR);
+        while transition.is_animating() {
+            transition.advance(Duration::from_millis(10));
+            assert!(transition.value().value().r <= 1.0);
+        }
+        assert_eq!(transition.value().value().r, 0.99);
+
+        // Out of range channels can still be constructed directly.
+        let target = Color {
+            r: 1.5,
+            ..Color::from_rgb(0.5, 0.5, 0.5)
+        };
+        let mut spring = Spring::new(Color::from_rgb(0.5, 0.5, 0.5)).to(target);
+        let mut now = Instant::now();
+        for _ in 0..1000 {
+            now += Duration::from_millis(16);
+            spring.tick(now);
+            assert!(spring.value().r <= 1.0 || spring.value() == &target);
+        }
+        assert!(!spring.has_energy());
+    }
+
+    /// Radii should animate each corner between uniform and asymmetric values.
+    #[test]
+    fn radius_round_trip() {
+        use crate::{animated::DEFAULT_DURATION, Easing, Transition};
+        use iced_core::border::Radius;
+
+        let uniform = Radius::from(4.0);
+        let asymmetric = Radius::from(4.0).top_left(12.0).bottom_right(0.0);
+        let mut transition = Transition::new(uniform)
+            .to(asymmetric)
+            .with_easing(Easing::LINEAR);
+
+        transition.advance(DEFAULT_DURATION / 2);
+        let halfway = *transition.value();
+        assert_eq!(halfway.top_left, 8.0);
+        assert_eq!(halfway.top_right, 4.0);
+        assert_eq!(halfway.bottom_right, 2.0);
+
+        transition.advance(DEFAULT_DURATION);
+        assert_eq!(*transition.value(), asymmetric);
+
+        transition.set_target(uniform);
+        transition.advance(DEFAULT_DURATION);
+        assert_eq!(*transition.value(), uniform);
+    }
+
+    /// Boxed values should animate the same as the unboxed value.
+    #[test]
+    fn boxed() {
+        use crate::{Easing, Transition};
+
+        let mut boxed = Transition::new(Box::new(0.0))
+            .to(Box::new(1.0))
+            .with_easing(Easing::EASE);
+        let mut unboxed = Transition::new(0.0).to(1.0).with_easing(Easing::EASE);
+        for _ in 0..4 {
+            boxed.advance(Duration::from_millis(100));
+            unboxed.advance(Duration::from_millis(100));
+            assert_eq!(**boxed.value(), *unboxed.value());
+        }
+        assert_eq!(Box::<f32>::components(), 1);
+        assert_consistent(&Box::new(0.0), &Box::new(1.0));
+    }
+
+    /// Shared values should animate without changing other owners of the same value.
+    #[test]
+    fn shared() {
+        use crate::{Easing, Spring, Transition};
+        use std::time::Instant;
+
+        let start = Arc::new(0.0);
+        let mut transition = Transition::new(start.clone())
+            .to(Arc::new(1.0))
+            .with_easing(Easing::LINEAR);
+        transition.advance(Duration::from_millis(250));
+        assert_eq!(**transition.value(), 0.5);
+        transition.advance(Duration::from_millis(250));
+        assert_eq!(**transition.value(), 1.0);
+        assert_eq!(*start, 0.0);
+
+        let start = Rc::new(0.0);
+        let mut spring = Spring::new(start.clone()).to(Rc::new(1.0));
+        let mut now = Instant::now();
+        while spring.has_energy() {
+            now += Duration::from_millis(16);
+            spring.tick(now);
+        }
+        assert_eq!(**spring.value(), 1.0);
+        assert_eq!(*start, 0.0);
+
+        let start: Cow<'static, f32> = Cow::Borrowed(&0.0);
+        let mut value = start.clone();
+        value.lerp(&start, &Cow::Owned(1.0), 0.5);
+        assert_eq!(*value, 0.5);
+        assert_eq!(*start, 0.0);
+    }
+
+    /// Color stops should animate their offset and color, keeping the offset in range.
+    #[test]
+    fn color_stop() {
+        use iced_core::{gradient::ColorStop, Color};
+
+        let start = ColorStop {
+            offset: 0.2,
+            color: Color::BLACK,
+        };
+        let end = ColorStop {
+            offset: 0.8,
+            color: Color::WHITE,
+        };
+        let mut stop = start;
+        stop.lerp(&start, &end, 0.5);
+        assert!((stop.offset - 0.5).abs() < 1e-6);
+        assert_eq!(stop.color, Color::from_rgb(0.5, 0.5, 0.5));
+
+        stop.update(&mut [1.0, 0.0, 0.0, 0.0, 0.0].into_iter());
+        assert_eq!(stop.offset, 1.0);
+        stop.lerp(&start, &end, -1.0);
+        assert_eq!(stop.offset, 0.0);
+    }
+
+    /// Highlight formats should animate their color while keeping their font.
+    #[test]
+    fn highlight_format() {
+        use crate::{Easing, Transition};
+        use iced_core::{text::highlighter::Format, Color, Font};
+
+        let start = Format {
+            color: Some(Color::BLACK),
+            font: Some(Font::MONOSPACE),
+        };
+        let end = Format {
+            color: Some(Color::WHITE),
+            font: Some(Font::MONOSPACE),
+        };
+        let mut transition = Transition::new(start).to(end).with_easing(Easing::LINEAR);
+        transition.advance(Duration::from_millis(250));
+        assert_eq!(
+            transition.value().color,
+            Some(Color::from_rgb(0.5, 0.5, 0.5))
+        );
+        assert_eq!(transition.value().font, Some(Font::MONOSPACE));
+
+        transition.advance(Duration::from_millis(250));
+        assert_eq!(*transition.value(), end);
+    }
+
+    /// Rotations should animate their angle and keep the starting variant until they settle,
+    /// whether they're interpolated or updated by a spring.
+    #[test]
+    fn rotation() {
+        use crate::Spring;
+        use iced_core::{Radians, Rotation};
+        use std::time::Instant;
+
+        let start = Rotation::Floating(Radians(0.0));
+        let end = Rotation::Floating(Radians::PI);
+        let mut rotation = start;
+        rotation.lerp(&start, &end, 0.5);
+        assert_eq!(rotation, Rotation::Floating(Radians::PI / 2.0));
+        assert!(start.can_animate_to(&end));
+
+        let solid = Rotation::Solid(Radians::PI);
+        assert!(!start.can_animate_to(&solid));
+        rotation.lerp(&start, &solid, 0.5);
+        assert_eq!(rotation, Rotation::Floating(Radians::PI / 2.0));
+        rotation.lerp(&start, &solid, 1.0);
+        assert_eq!(rotation, solid);
+
+        let mut spring = Spring::new(start).to(solid);
+        let mut now = Instant::now();
+        now += Duration::from_millis(16);
+        spring.tick(now);
+        assert!(matches!(spring.value(), Rotation::Floating(_)));
+        while spring.has_energy() {
+            now += Duration::from_millis(16);
+            spring.tick(now);
+        }
+        assert_eq!(*spring.value(), solid);
+    }
+
+    /// Pixels should animate their inner value without going negative.
+    #[test]
+    fn pixels() {
+        use iced_core::Pixels;
+
+        let start = Pixels(10.0);
+        let end = Pixels(20.0);
+        let mut pixels = start;
+        pixels.lerp(&start, &end, 0.5);
+        assert_eq!(pixels, Pixels(15.0));
+
+        pixels.update(&mut [-20.0].into_iter());
+        assert_eq!(pixels, Pixels(0.0));
+    }
+
     #[test]
     fn f32_point_components() {
         assert_eq!(iced_core::Point::<f32>::components(), 2);
@@ -1074,6 +1836,32 @@ mod tests {
         );
     }
 
+    /// Animating between built-in themes should blend their palettes in a custom theme.
+    #[test]
+    fn theme_midpoint() {
+        let (light, dark) = (Theme::Light, Theme::Dark);
+        let mut theme = light.clone();
+        theme.lerp(&light, &dark, 0.5);
+        assert!(matches!(theme, Theme::Custom(_)));
+
+        let (start, end) = (light.palette().background, dark.palette().background);
+        let middle = theme.palette().background;
+        for (start, middle, end) in [
+            (start.r, middle.r, end.r),
+            (start.g, middle.g, end.g),
+            (start.b, middle.b, end.b),
+        ] {
+            assert!((middle - (start + end) / 2.0).abs() < 1e-6);
+        }
+
+        let extended = theme.extended_palette().background.base.color;
+        let (start, end) = (
+            light.extended_palette().background.base.color,
+            dark.extended_palette().background.base.color,
+        );
+        assert!(extended.r < start.r.max(end.r) && extended.r > start.r.min(end.r));
+    }
+
     #[test]
     fn option_components() {
         assert_eq!(Option::<f32>::components(), 1);
@@ -1114,6 +1902,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "widgets")]
     fn update_button_style() {
         let style = iced_widget::button::Style {
             background: Some(iced_core::Background::Color(iced_core::Color::BLACK)),
@@ -1135,4 +1924,127 @@ mod tests {
         spring.tick(std::time::Instant::now());
         assert_ne!(*spring.value(), style);
     }
+
+    /// Button styles should interpolate every part of the style together.
+    #[test]
+    #[cfg(feature = "widgets")]
+    fn button_style_midpoint() {
+        use iced_core::{Background, Border, Color};
+
+        let start = iced_widget::button::Style {
+            background: Some(Background::Color(Color::BLACK)),
+            text_color: Color::WHITE,
+            border: Border::default(),
+            ..Default::default()
+        };
+        let end = iced_widget::button::Style {
+            background: Some(Background::Color(Color::WHITE)),
+            text_color: Color::BLACK,
+            border: Border::default().width(2.0).rounded(8),
+            ..Default::default()
+        };
+
+        let mut style = start;
+        style.lerp(&start, &end, 0.5);
+
+        let gray = Color::from_rgb(0.5, 0.5, 0.5);
+        assert_eq!(style.background, Some(Background::Color(gray)));
+        assert_eq!(style.text_color, gray);
+        assert_eq!(style.border.width, 1.0);
+        assert_eq!(style.border.radius.top_left, 4.0);
+    }
+
+    /// Text input styles should reach their target when updated by the distance to it.
+    #[test]
+    #[cfg(feature = "widgets")]
+    fn update_text_input_style() {
+        use iced_core::{Background, Border, Color};
+
+        let start = iced_widget::text_input::Style {
+            background: Background::Color(Color::WHITE),
+            border: Border::default(),
+            icon: Color::BLACK,
+            placeholder: Color::BLACK,
+            value: Color::BLACK,
+            selection: Color::BLACK,
+        };
+        let end = iced_widget::text_input::Style {
+            background: Background::Color(Color::BLACK),
+            border: Border::default().width(1.0),
+            icon: Color::WHITE,
+            placeholder: Color::WHITE,
+            value: Color::WHITE,
+            selection: Color::WHITE,
+        };
+
+        let mut style = start;
+        style.update(&mut end.distance_to(&start).into_iter());
+        assert_eq!(style, end);
+    }
+
+    /// The built-in implementations should all be consistent with themselves.
+    #[test]
+    fn built_in_impls_are_consistent() {
+        use iced_core::{
+            gradient::Linear, Background, Border, Color, Point, Radians, Rectangle, Shadow, Size,
+            Vector,
+        };
+
+        assert_consistent(&0.0, &1.0);
+        assert_consistent(&'a', &'z');
+        assert_consistent(&Duration::ZERO, &Duration::from_secs(1));
+        assert_consistent(&Point::ORIGIN, &Point::new(1.0, 2.0));
+        assert_consistent(&Color::BLACK, &Color::WHITE);
+        assert_consistent(&Theme::Light, &Theme::Dark);
+        assert_consistent(&Some(1.0), &None);
+        assert_consistent(&Border::default(), &Border::default().width(1.0).rounded(4));
+        assert_consistent(&Shadow::default(), &Shadow::default());
+        assert_consistent(&Vector::new(0.0, 0.0), &Vector::new(1.0, 1.0));
+        assert_consistent(&Size::ZERO, &Size::new(1.0, 1.0));
+        assert_consistent(
+            &Rectangle::default(),
+            &Rectangle::new(Point::ORIGIN, Size::UNIT),
+        );
+        assert_consistent(&Radians(0.0), &Radians(1.0));
+        assert_consistent(
+            &iced_core::Rotation::Floating(Radians(0.0)),
+            &iced_core::Rotation::Solid(Radians(1.0)),
+        );
+        assert_consistent(&[0.0; 3], &[1.0; 3]);
+        assert_consistent(
+            &Background::Color(Color::BLACK),
+            &Background::Gradient(Linear::new(Radians(0.0)).into()),
+        );
+        assert_consistent(&(0.0, Color::BLACK), &(1.0, Color::WHITE));
+        assert_consistent(&(0.0, 1.0, 2.0), &(1.0, 2.0, 3.0));
+        assert_consistent(&(0.0, 1.0, 2.0, 3.0), &(1.0, 2.0, 3.0, 4.0));
+    }
+
+    /// Implementations that consume the wrong number of components should be caught.
+    #[test]
+    #[should_panic(expected = "`update` consumed 2 components")]
+    fn inconsistent_update_panics() {
+        #[derive(Clone, PartialEq)]
+        struct Broken(f32);
+
+        impl Animate for Broken {
+            fn components() -> usize {
+                1
+            }
+
+            fn update(&mut self, components: &mut impl Iterator<Item = f32>) {
+                self.0 += components.next().unwrap() + components.next().unwrap();
+            }
+
+            fn distance_to(&self, end: &Self) -> Vec<f32> {
+                self.0.distance_to(&end.0)
+            }
+
+            fn lerp(&mut self, start: &Self, end: &Self, progress: f32) {
+                self.0.lerp(&start.0, &end.0, progress);
+            }
+        }
+
+        assert_consistent(&Broken(0.0), &Broken(1.0));
+    }
 }
@@ -118,7 +118,9 @@ where
     pub fn set_target(&mut self, target: T) {
         match &mut self.animation {
             AnimationType::Spring(spring) => spring.set_target(target),
-            AnimationType::Transition(transition) => transition.set_target(target),
+            AnimationType::Transition(transition) => {
+                transition.set_target(target);
+            }
         }
     }
 
@@ -126,7 +128,9 @@ where
     pub fn to(mut self, target: T) -> Self {
         match &mut self.animation {
             AnimationType::Spring(spring) => spring.set_target(target),
-            AnimationType::Transition(transition) => transition.set_target(target),
+            AnimationType::Transition(transition) => {
+                transition.set_target(target);
+            }
         }
 
         self
@@ -140,6 +144,15 @@ where
         }
     }
 
+    /// Returns how far a transition is towards its current target, where `0.0` is the start
+    /// and `1.0` is the end. Springs don't have a fixed end, so this is `None` for springs.
+    pub fn progress_value(&self) -> Option<f32> {
+        match &self.animation {
+            AnimationType::Spring(_) => None,
+            AnimationType::Transition(transition) => Some(transition.progress_value()),
+        }
+    }
+
     /// Applies the given `config` to this animation, updating any duration/motion/curve settings.
     /// Changing modes will reset the animation, interrupting any existing animation curve.
     pub(crate) fn apply(&mut self, mode: Mode) {
@@ -191,6 +204,14 @@ where
             AnimationType::Transition(transition) => transition.tick(now),
         }
     }
+
+    /// Moves the animation forward by the given `elapsed` time, without reading the clock.
+    pub fn advance(&mut self, elapsed: Duration) {
+        match &mut self.animation {
+            AnimationType::Spring(spring) => spring.advance(elapsed),
+            AnimationType::Transition(transition) => transition.advance(elapsed),
+        }
+    }
 }
 
 impl<T> From<T> for Animated<T>
@@ -84,6 +84,7 @@ use iced_core::{
     widget::{tree, Tree},
     Element, Length, Rectangle, Size, Widget,
 };
+use std::time::Instant;
 
 /// A widget that implicitly animates a value anytime it changes.
 ///
@@ -108,6 +109,12 @@ where
     is_disabled: bool,
     /// The cached element built using the most recent animated value and `builder`.
     cached_element: Element<'a, Message, Theme, Renderer>,
+    /// Resolves the target against the space available during layout, if any.
+    resolve: Option<Box<dyn Fn(T, Size) -> T + 'a>>,
+    /// Produces a message with the animated value on each frame, if any.
+    on_interpolate: Option<Box<dyn Fn(T) -> Message + 'a>>,
+    /// The current time when the animation is driven by the app instead of window redraws.
+    clock: Option<Instant>,
 }
 
 impl<'a, T, Message, Theme, Renderer> AnimationBuilder<'a, T, Message, Theme, Renderer>
@@ -127,6 +134,9 @@ where
             mode: Mode::default(),
             animates_layout: false,
             is_disabled: false,
+            resolve: None,
+            on_interpolate: None,
+            clock: None,
         }
     }
 
@@ -153,6 +163,48 @@ where
         self.is_disabled = disabled;
         self
     }
+
+    /// Resolves the target value against the maximum space available to this widget during
+    /// layout, which is given to the `resolve` function along with the target.
+    ///
+    /// This lets the animated value depend on the measured layout, e.g. animating an
+    /// [`AnimatedLength`](crate::length::AnimatedLength) towards [`Length::Fill`].
+    pub fn resolve_target(mut self, resolve: impl Fn(T, Size) -> T + 'a) -> Self {
+        self.resolve = Some(Box::new(resolve));
+        self
+    }
+
+    /// Publishes the message produced by `on_interpolate` with the animated value on each frame
+    /// of the animation.
+    ///
+    /// With a [clock](AnimationBuilder::clock), the message is published when the window is
+    /// redrawn after a view advanced the animation.
+    ///
+    /// The animated value is otherwise only visible inside the builder's closure, so this lets
+    /// it drive other parts of the view, e.g. positioning a sibling next to a growing box.
+    pub fn on_interpolate(mut self, on_interpolate: impl Fn(T) -> Message + 'a) -> Self {
+        self.on_interpolate = Some(Box::new(on_interpolate));
+        self
+    }
+
+    /// Drives the animation with the given time instead of window redraws.
+    ///
+    /// By default, the animation advances whenever the window is redrawn. With a clock, the
+    /// animation instead advances by the time between the clocks of consecutive views, so it
+    /// can be driven by the app, e.g. from its own frame subscription, for offscreen rendering,
+    /// or with synthetic times in tests. The app is responsible for requesting new frames.
+    pub fn clock(mut self, now: Instant) -> Self {
+        self.clock = Some(now);
+        self
+    }
+
+    /// The target value, resolved against the `available` space if it's known.
+    fn resolved_target(&self, available: Option<Size>) -> T {
+        match (&self.resolve, available) {
+            (Some(resolve), Some(available)) => resolve(self.target.clone(), available),
+            _ => self.target.clone(),
+        }
+    }
 }
 
 impl<'a, T, Message, Theme, Renderer> From<AnimationBuilder<'a, T, Message, Theme, Renderer>>
@@ -171,6 +223,12 @@ where
 struct State<T> {
     animation: Animated<T>,
     mode: Mode,
+    /// The maximum space available during the most recent layout.
+    available: Option<Size>,
+    /// The clock of the most recent layout, when driven by the app and animating.
+    clock: Option<Instant>,
+    /// Whether a layout driven by the app's clock advanced the animation since the last redraw.
+    is_interpolated: bool,
 }
 
 impl<T, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
@@ -198,6 +256,9 @@ where
         tree::State::new(State {
             animation: Animated::new(self.target.clone(), self.mode),
             mode: self.mode,
+            available: None,
+            clock: None,
+            is_interpolated: false,
         })
     }
 
@@ -208,11 +269,12 @@ where
     fn diff(&self, tree: &mut Tree) {
         // Update the spring's target if it has changed
         let state = tree.state.downcast_mut::<State<T>>();
-        if state.animation.target() != &self.target {
+        let target = self.resolved_target(state.available);
+        if state.animation.target() != &target {
             if self.is_disabled {
                 state.animation.settle();
             } else {
-                state.animation.set_target(self.target.clone());
+                state.animation.set_target(target);
             }
         }
 
@@ -230,6 +292,45 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
+        if self.resolve.is_some() {
+            let state = tree.state.downcast_mut::<State<T>>();
+            let is_first_layout = state.available.is_none();
+            state.available = Some(limits.max());
+
+            let target = self.resolved_target(state.available);
+            if is_first_layout || !state.animation.is_animating() {
+                // Keep the resting value in sync with the measured space without animating.
+                let is_changed = state.animation.value() != &target;
+                state.animation.settle_at(target);
+                if is_changed {
+                    self.cached_element = (self.builder)(state.animation.value().clone());
+                    tree.diff_children(std::slice::from_ref(&self.cached_element));
+                }
+            } else if state.animation.target() != &target {
+                state.animation.set_target(target);
+            }
+        }
+
+        if let Some(now) = self.clock {
+            let state = tree.state.downcast_mut::<State<T>>();
+
+            // The clock is only kept while animating, so an animation that starts after being
+            // idle doesn't skip ahead by the time it spent at rest.
+            if state.animation.is_animating() {
+                let elapsed = state
+                    .clock
+                    .map(|last| now.saturating_duration_since(last))
+                    .unwrap_or_default();
+                state.animation.advance(elapsed);
+                state.clock = state.animation.is_animating().then_some(now);
+                state.is_interpolated = true;
+                self.cached_element = (self.builder)(state.animation.value().clone());
+                tree.diff_children(std::slice::from_ref(&self.cached_element));
+            } else {
+                state.clock = None;
+            }
+        }
+
         self.cached_element
             .as_widget_mut()
             .layout(&mut tree.children[0], renderer, limits)
@@ -337,8 +438,8 @@ where
 
         let state = tree.state.downcast_mut::<State<T>>();
 
-        // Request a redraw if the spring has remaining energy
-        if state.animation.is_animating() {
+        // Request a redraw if the spring has remaining energy, unless the app drives the clock.
+        if self.clock.is_none() && state.animation.is_animating() {
             shell.request_redraw();
             // Only invalidate the layout if the user indicates to do so
             if self.animates_layout {
@@ -348,6 +449,16 @@ where
             // Update the animation and request a redraw
             state.animation.tick(*now);
             self.cached_element = (self.builder)(state.animation.value().clone());
+
+            if let Some(on_interpolate) = &self.on_interpolate {
+                shell.publish(on_interpolate(state.animation.value().clone()));
+            }
+        } else if self.clock.is_some() && state.is_interpolated {
+            // The app's clock advances the animation during layout, which can't publish messages.
+            state.is_interpolated = false;
+            if let Some(on_interpolate) = &self.on_interpolate {
+                shell.publish(on_interpolate(state.animation.value().clone()));
+            }
         }
     }
 }
@@ -362,3 +473,111 @@ where
 {
     AnimationBuilder::new(value, builder)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{length::AnimatedLength, transition::Easing};
+    use iced_core::{clipboard, mouse, widget::Text, window, Color, Event, Layout, Shell};
+    use std::{
+        cell::RefCell,
+        rc::Rc,
+        time::{Duration, Instant},
+    };
+
+    /// The builder closure should receive interpolated colors while animating.
+    #[test]
+    fn builder_receives_interpolated_colors() {
+        let received = Rc::new(RefCell::new(Vec::new()));
+        let builder = |target: Color| {
+            let received = received.clone();
+            AnimationBuilder::<_, (), iced_core::Theme, ()>::new(target, move |color| {
+                received.borrow_mut().push(color);
+                Text::new("").into()
+            })
+            .animation(Easing::LINEAR.with_duration(Duration::from_millis(100)))
+        };
+
+        let mut tree = Tree::new(&builder(Color::BLACK) as &dyn Widget<_, _, _>);
+        let mut widget = builder(Color::WHITE);
+        widget.diff(&mut tree);
+
+        let node = layout::Node::new(Size::ZERO);
+        let mut messages = Vec::new();
+        let mut shell = Shell::new(&mut messages);
+        let now = Instant::now() + Duration::from_millis(50);
+        widget.update(
+            &mut tree,
+            &Event::Window(window::Event::RedrawRequested(now)),
+            Layout::new(&node),
+            mouse::Cursor::Unavailable,
+            &(),
+            &mut clipboard::Null,
+            &mut shell,
+            &Rectangle::default(),
+        );
+
+        let color = *received.borrow().last().expect("builder should be invoked");
+        assert!(color.r > 0.0 && color.r < 1.0);
+        assert_eq!(color.r, color.g);
+        assert_eq!(color.g, color.b);
+    }
+
+    /// Animating towards a filling length should animate towards the measured space,
+    /// then become a true fill once settled.
+    #[test]
+    fn resolves_fill_against_available_space() {
+        let received = Rc::new(RefCell::new(Vec::new()));
+        let builder = |target: AnimatedLength| {
+            let received = received.clone();
+            AnimationBuilder::<_, (), iced_core::Theme, ()>::new(target, move |length| {
+                received.borrow_mut().push(length);
+                Text::new("").into()
+            })
+            .resolve_target(|length, available| length.resolve(available.width))
+            .animation(Easing::LINEAR.with_duration(Duration::from_millis(100)))
+        };
+        let limits = layout::Limits::new(Size::ZERO, Size::new(300.0, 100.0));
+        let redraw = |widget: &mut AnimationBuilder<_, (), _, ()>, tree: &mut Tree, now| {
+            let node = layout::Node::new(Size::ZERO);
+            let mut messages = Vec::new();
+            widget.update(
+                tree,
+                &Event::Window(window::Event::RedrawRequested(now)),
+                Layout::new(&node),
+                mouse::Cursor::Unavailable,
+                &(),
+                &mut clipboard::Null,
+                &mut Shell::new(&mut messages),
+                &Rectangle::default(),
+            );
+        };
+
+        let mut widget = builder(AnimatedLength::fixed(0.0));
+        let mut tree = Tree::new(&widget as &dyn Widget<_, _, _>);
+        let _ = widget.layout(&mut tree, &(), &limits);
+
+        let mut widget = builder(AnimatedLength::fill());
+        widget.diff(&mut tree);
+        let _ = widget.layout(&mut tree, &(), &limits);
+
+        redraw(
+            &mut widget,
+            &mut tree,
+            Instant::now() + Duration::from_millis(50),
+        );
+        let halfway = *received.borrow().last().unwrap();
+        assert!(!halfway.is_fill());
+        assert!(halfway.pixels() > 0.0 && halfway.pixels() < 300.0);
+
+        redraw(
+            &mut widget,
+            &mut tree,
+            Instant::now() + Duration::from_millis(200),
+        );
+        let done = *received.borrow().last().unwrap();
+        assert!(done.is_fill());
+        assert_eq!(done.pixels(), 300.0);
+        assert_eq!(done.length(), Length::Fill);
+    }
+}
@@ -0,0 +1,238 @@
+//! A keyed collection of transitions, e.g. for animating each entry in a map of widget states.
+//!
+//! Each key has its own [`Transition`], so entries animate independently of each other and the
+//! order of the keys doesn't matter. Keys that are added enter from the default value, and keys
+//! that are removed exit towards the default value before being dropped:
+//!
+//! ```rust
+//! # use iced_anim::{AnimationMap, Easing};
+//! # use std::collections::HashMap;
+//! let mut map = AnimationMap::new(Easing::EASE);
+//! map.set_target(HashMap::from([("a", 1.0), ("b", 2.0)]));
+//!
+//! // Both entries start from the default value.
+//! assert_eq!(map.get(&"a"), Some(&0.0));
+//!
+//! // Removed entries are kept until they've finished exiting.
+//! map.set_target(HashMap::from([("a", 1.0)]));
+//! assert!(map.is_exiting(&"b"));
+//! ```
+use crate::{Animate, Easing, Transition};
+use std::{
+    collections::{HashMap, HashSet},
+    hash::Hash,
+    time::{Duration, Instant},
+};
+
+/// A collection of [`Transition`]s identified by key, which animates entries in and out as keys
+/// are added and removed.
+///
+/// [`Animate`] can't be implemented for a map itself since [`Animate::components`] is the same
+/// for every value of a type, while a map's entries change over time.
+#[derive(Debug, Clone)]
+pub struct AnimationMap<K, V> {
+    /// The transition of each entry, including entries that are exiting.
+    transitions: HashMap<K, Transition<V>>,
+    /// The keys that were removed and are animating towards the default value.
+    exiting: HashSet<K>,
+    /// The easing used for the transitions of new entries.
+    easing: Easing,
+}
+
+impl<K, V> AnimationMap<K, V>
+where
+    K: Eq + Hash + Clone,
+    V: Animate + Default,
+{
+    /// Creates a new, empty [`AnimationMap`] whose entries animate with the given `easing`.
+    pub fn new(easing: Easing) -> Self {
+        Self {
+            transitions: HashMap::new(),
+            exiting: HashSet::new(),
+            easing,
+        }
+    }
+
+    /// Animates each entry towards the matching value in `target`.
+    ///
+    /// Keys that aren't in the map yet animate in from the default value, and keys that are
+    /// missing from `target` animate out towards the default value before being dropped.
+    pub fn set_target(&mut self, target: impl IntoIterator<Item = (K, V)>) {
+        let mut removed: HashSet<K> = self.transitions.keys().cloned().collect();
+        for (key, value) in target {
+            removed.remove(&key);
+            self.insert(key, value);
+        }
+
+        for key in removed {
+            self.remove(&key);
+        }
+    }
+
+    /// Animates the entry for `key` towards `value`, entering from the default value if the key
+    /// isn't in the map. An entry that's exiting animates back towards `value` instead.
+    pub fn insert(&mut self, key: K, value: V) {
+        self.exiting.remove(&key);
+        match self.transitions.get_mut(&key) {
+            Some(transition) => {
+                transition.set_target(value);
+            }
+            None => {
+                let transition = Transition::new(V::default())
+                    .with_easing(self.easing)
+                    .to(value);
+                self.transitions.insert(key, transition);
+            }
+        }
+    }
+
+    /// Animates the entry for `key` towards the default value, dropping it once it settles.
+    pub fn remove(&mut self, key: &K) {
+        if let Some(transition) = self.transitions.get_mut(key) {
+            transition.set_target(V::default());
+            self.exiting.insert(key.clone());
+        }
+        self.drop_exited();
+    }
+
+    /// Returns the current value of the entry for `key`, if any.
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.transitions.get(key).map(Transition::value)
+    }
+
+    /// Returns the transition of the entry for `key`, if any.
+    pub fn transition(&self, key: &K) -> Option<&Transition<V>> {
+        self.transitions.get(key)
+    }
+
+    /// Whether the entry for `key` was removed and is still animating out.
+    pub fn is_exiting(&self, key: &K) -> bool {
+        self.exiting.contains(key)
+    }
+
+    /// Returns an iterator over the keys and current values of the entries, including entries
+    /// that are exiting, in an unspecified order.
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> {
+        self.transitions
+            .iter()
+            .map(|(key, transition)| (key, transition.value()))
+    }
+
+    /// The number of entries in the map, including entries that are exiting.
+    pub fn len(&self) -> usize {
+        self.transitions.len()
+    }
+
+    /// Whether the map doesn't contain any entries.
+    pub fn is_empty(&self) -> bool {
+        self.transitions.is_empty()
+    }
+
+    /// Whether any of the entries in the map are still animating.
+    pub fn is_animating(&self) -> bool {
+        self.transitions.values().any(Transition::is_animating)
+    }
+
+    /// Updates every entry based on the elapsed time since it was last updated.
+    pub fn tick(&mut self, now: Instant) {
+        for transition in self.transitions.values_mut() {
+            transition.tick(now);
+        }
+        self.drop_exited();
+    }
+
+    /// Moves every entry forward by the given `elapsed` time.
+    pub fn advance(&mut self, elapsed: Duration) {
+        for transition in self.transitions.values_mut() {
+            transition.advance(elapsed);
+        }
+        self.drop_exited();
+    }
+
+    /// Drops the entries that have finished exiting.
+    fn drop_exited(&mut self) {
+        let transitions = &mut self.transitions;
+        self.exiting.retain(|key| {
+            let is_exited = transitions
+                .get(key)
+                .is_none_or(|transition| !transition.is_animating());
+            if is_exited {
+                transitions.remove(key);
+            }
+            !is_exited
+        });
+    }
+}
+
+impl<K, V> Default for AnimationMap<K, V>
+where
+    K: Eq + Hash + Clone,
+    V: Animate + Default,
+{
+    fn default() -> Self {
+        Self::new(Easing::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::animated::DEFAULT_DURATION;
+
+    /// Entries should enter from the default, change in place, and exit before being dropped.
+    #[test]
+    fn add_change_and_remove_keys() {
+        let mut map = AnimationMap::new(Easing::LINEAR);
+        map.set_target([("a", 1.0), ("b", 2.0)]);
+        assert_eq!(map.len(), 2);
+        assert_eq!(map.get(&"a"), Some(&0.0));
+        assert!(map.is_animating());
+
+        map.advance(DEFAULT_DURATION / 2);
+        assert_eq!(map.get(&"a"), Some(&0.5));
+        assert_eq!(map.get(&"b"), Some(&1.0));
+
+        // Change one key, remove another, and add a new one.
+        map.set_target([("a", 3.0), ("c", 4.0)]);
+        assert!(map.is_exiting(&"b"));
+        assert_eq!(map.get(&"c"), Some(&0.0));
+
+        map.advance(DEFAULT_DURATION / 2);
+        assert_eq!(map.get(&"a"), Some(&1.75));
+        assert_eq!(map.get(&"b"), Some(&0.5));
+        assert_eq!(map.get(&"c"), Some(&2.0));
+
+        map.advance(DEFAULT_DURATION);
+        assert!(!map.is_animating());
+        assert_eq!(map.get(&"a"), Some(&3.0));
+        assert_eq!(map.get(&"b"), None);
+        assert_eq!(map.get(&"c"), Some(&4.0));
+        assert_eq!(map.len(), 2);
+    }
+
+    /// Adding a key back while it's exiting should animate it back instead of dropping it.
+    #[test]
+    fn insert_while_exiting() {
+        let mut map = AnimationMap::new(Easing::LINEAR);
+        map.insert("a", 1.0);
+        map.advance(DEFAULT_DURATION);
+
+        map.remove(&"a");
+        map.advance(DEFAULT_DURATION / 2);
+        assert!(map.is_exiting(&"a"));
+
+        map.insert("a", 1.0);
+        assert!(!map.is_exiting(&"a"));
+        map.advance(DEFAULT_DURATION);
+        assert_eq!(map.get(&"a"), Some(&1.0));
+    }
+
+    /// Removing an entry that's already at the default value should drop it immediately.
+    #[test]
+    fn remove_settled_default() {
+        let mut map = AnimationMap::<_, f32>::default();
+        map.insert("a", 0.0);
+        map.remove(&"a");
+        assert!(map.is_empty());
+    }
+}
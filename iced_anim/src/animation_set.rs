@@ -0,0 +1,234 @@
+//! A collection of animated values that are updated together.
+//!
+//! Apps with many independent animations need to keep requesting frames while any of them is
+//! still moving. Rather than checking each value, store them in an [`AnimationSet`] and use
+//! [`AnimationSet::is_animating`] to decide whether to keep the frame subscription alive:
+//!
+//! ```rust
+//! # use iced_anim::{Animated, AnimationSet, Easing, Event};
+//! let mut set = AnimationSet::new();
+//! let opacity = set.push(Animated::transition(0.0, Easing::EASE));
+//! let scale = set.push(Animated::transition(1.0, Easing::EASE));
+//!
+//! set.get_mut(opacity).unwrap().set_target(1.0);
+//! assert!(set.is_animating());
+//!
+//! // Update every value at once, e.g. on each frame.
+//! set.update(Event::tick());
+//! ```
+use crate::{Animate, Animated, Event};
+use std::time::{Duration, Instant};
+
+/// A collection of [`Animated`] values that can be checked and updated together.
+///
+/// Values are identified by the index returned from [`AnimationSet::push`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct AnimationSet<T> {
+    /// The animated values in the order they were added.
+    animations: Vec<Animated<T>>,
+    /// The time at which the set was last updated by [`AnimationSet::tick_all`].
+    last_update: Instant,
+    /// Whether any value was still animating after the last [`AnimationSet::tick_all`].
+    was_animating: bool,
+}
+
+impl<T> AnimationSet<T>
+where
+    T: Animate,
+{
+    /// Creates a new, empty [`AnimationSet`].
+    pub fn new() -> Self {
+        Self {
+            animations: Vec::new(),
+            last_update: Instant::now(),
+            was_animating: false,
+        }
+    }
+
+    /// Adds an animated value to the set and returns its index.
+    pub fn push(&mut self, animated: impl Into<Animated<T>>) -> usize {
+        self.animations.push(animated.into());
+        self.animations.len() - 1
+    }
+
+    /// Returns a reference to the animated value at the given `index`, if any.
+    pub fn get(&self, index: usize) -> Option<&Animated<T>> {
+        self.animations.get(index)
+    }
+
+    /// Returns a mutable reference to the animated value at the given `index`, if any.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut Animated<T>> {
+        self.animations.get_mut(index)
+    }
+
+    /// Returns an iterator over the animated values in the set.
+    pub fn iter(&self) -> impl Iterator<Item = &Animated<T>> {
+        self.animations.iter()
+    }
+
+    /// The number of animated values in the set.
+    pub fn len(&self) -> usize {
+        self.animations.len()
+    }
+
+    /// Whether the set doesn't contain any animated values.
+    pub fn is_empty(&self) -> bool {
+        self.animations.is_empty()
+    }
+
+    /// Whether any of the values in the set are still animating.
+    pub fn is_animating(&self) -> bool {
+        self.animations.iter().any(Animated::is_animating)
+    }
+
+    /// Moves every value in the set forward by the time elapsed since the set was last ticked.
+    ///
+    /// Unlike [`AnimationSet::tick`], the elapsed time is computed once and shared by every value,
+    /// which keeps a group of values in step and avoids measuring the time for each of them.
+    /// Avoid mixing this with [`AnimationSet::tick`] or [`AnimationSet::update`] on the same set.
+    ///
+    /// Frames usually stop while the set is idle, so the first tick after the set was settled
+    /// only starts the clock instead of applying the whole idle gap to the new animations.
+    pub fn tick_all(&mut self, now: Instant) {
+        if !self.was_animating {
+            self.last_update = now;
+        }
+
+        let elapsed = now.saturating_duration_since(self.last_update);
+        self.last_update = now;
+        self.advance(elapsed);
+        self.was_animating = self.is_animating();
+    }
+
+    /// Moves every value in the set forward by the given `elapsed` time.
+    pub fn advance(&mut self, elapsed: Duration) {
+        for animated in &mut self.animations {
+            animated.advance(elapsed);
+        }
+    }
+
+    /// Returns the transition that has progressed the least, if any.
+    ///
+    /// This is useful for keeping a group of transitions visually coherent by advancing the one
+    /// that's furthest behind first. Springs are skipped since they don't track progress.
+    pub fn least_progressed(&self) -> Option<&Animated<T>> {
+        self.animations
+            .iter()
+            .filter_map(|animated| Some((animated.progress_value()?, animated)))
+            .min_by(|(a, _), (b, _)| a.total_cmp(b))
+            .map(|(_, animated)| animated)
+    }
+
+    /// Updates every value in the set with the given `event`.
+    pub fn update(&mut self, event: Event<T>) {
+        for animated in &mut self.animations {
+            animated.update(event.clone());
+        }
+    }
+
+    /// Updates every value in the set based on the elapsed time since it was last updated.
+    pub fn tick(&mut self, now: Instant) {
+        for animated in &mut self.animations {
+            animated.tick(now);
+        }
+    }
+}
+
+impl<T> Default for AnimationSet<T>
+where
+    T: Animate,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{animated::DEFAULT_DURATION, spring::Motion, Easing};
+
+    /// The set should be animating until every value has settled.
+    #[test]
+    fn animating_until_all_settle() {
+        let mut set = AnimationSet::new();
+        assert!(!set.is_animating());
+
+        let quick = set.push(Animated::transition(0.0, Easing::LINEAR).quick());
+        let slow = set.push(Animated::transition(0.0, Easing::LINEAR).very_slow());
+        set.get_mut(quick).unwrap().set_target(1.0);
+        set.get_mut(slow).unwrap().set_target(1.0);
+        assert!(set.is_animating());
+
+        // The quick animation finishes first, but the set is still animating.
+        let start = Instant::now();
+        set.tick(start + DEFAULT_DURATION / 2);
+        assert!(!set.get(quick).unwrap().is_animating());
+        assert!(set.get(slow).unwrap().is_animating());
+        assert!(set.is_animating());
+
+        set.update(Event::Tick(start + DEFAULT_DURATION * 2));
+        assert!(!set.is_animating());
+        assert_eq!(set.get(slow).unwrap().value(), &1.0);
+    }
+
+    /// The transition with the smallest progress should be the least progressed.
+    #[test]
+    fn least_progressed() {
+        let mut set = AnimationSet::new();
+        assert_eq!(set.least_progressed(), None);
+
+        set.push(Animated::spring(0.0, Motion::default()).to(1.0));
+        let ahead = set.push(Animated::transition(0.0, Easing::LINEAR).quick());
+        let behind = set.push(Animated::transition(0.0, Easing::LINEAR).very_slow());
+        let middle = set.push(Animated::transition(0.0, Easing::LINEAR).slow());
+        for index in [ahead, behind, middle] {
+            set.get_mut(index).unwrap().set_target(1.0);
+        }
+
+        set.tick(Instant::now() + Duration::from_millis(100));
+        let progress = |index| set.get(index).unwrap().progress_value().unwrap();
+        assert!(progress(ahead) > progress(middle));
+        assert!(progress(middle) > progress(behind));
+        assert_eq!(set.least_progressed(), set.get(behind));
+    }
+
+    /// Ticking every value at once should share a single elapsed time between them.
+    #[test]
+    fn tick_all_shares_elapsed_time() {
+        let mut set = AnimationSet::new();
+        let start = Instant::now();
+        let indices: Vec<_> = (0..100)
+            .map(|_| set.push(Animated::transition(0.0, Easing::LINEAR).to(1.0)))
+            .collect();
+
+        set.tick_all(start);
+        set.tick_all(start + DEFAULT_DURATION / 4);
+        for index in indices {
+            let animated = set.get(index).unwrap();
+            assert_eq!(animated.progress_value(), Some(0.25));
+            assert_eq!(animated.value(), &0.25);
+        }
+
+        set.tick_all(start + DEFAULT_DURATION);
+        assert!(!set.is_animating());
+    }
+
+    /// Ticking every value after the set was idle shouldn't apply the idle time to new targets.
+    #[test]
+    fn tick_all_after_idle() {
+        let mut set = AnimationSet::new();
+        let index = set.push(Animated::transition(0.0, Easing::LINEAR));
+        let start = Instant::now();
+        set.tick_all(start);
+
+        // Wait a while before animating, when no frames are being requested.
+        let later = start + DEFAULT_DURATION * 10;
+        set.get_mut(index).unwrap().set_target(1.0);
+        set.tick_all(later);
+        assert_eq!(set.get(index).unwrap().value(), &0.0);
+
+        set.tick_all(later + DEFAULT_DURATION / 2);
+        assert_eq!(set.get(index).unwrap().value(), &0.5);
+    }
+}
@@ -0,0 +1,371 @@
+//! Colors that interpolate in different color spaces.
+//!
+//! [`Color`] animates each sRGB channel separately, which is cheap but tends to pass through
+//! dull, grayish colors when animating between different hues. Wrap a color in an
+//! [`InterpolatedColor`] to pick the [`Interpolation`] used to animate it instead:
+//!
+//! ```rust
+//! # use iced_core::Color;
+//! use iced_anim::{color::{InterpolatedColor, Interpolation}, Animate};
+//!
+//! let red = InterpolatedColor::new(Color::from_rgb(1.0, 0.0, 0.0), Interpolation::Oklch);
+//! let green = InterpolatedColor::new(Color::from_rgb(0.0, 1.0, 0.0), Interpolation::Oklch);
+//!
+//! let mut color = red;
+//! color.lerp(&red, &green, 0.5);
+//! let color: Color = color.into();
+//! ```
+use crate::Animate;
+use iced_core::Color;
+use std::f32::consts::{PI, TAU};
+
+/// The color space used to interpolate an [`InterpolatedColor`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Interpolation {
+    /// Interpolates the gamma-encoded sRGB channels, which is the same as animating a [`Color`].
+    #[default]
+    Srgb,
+    /// Interpolates the sRGB channels in linear light, which avoids dark midpoints.
+    LinearRgb,
+    /// Interpolates lightness, chroma, and hue in the perceptually uniform OKLCH color space.
+    /// Hues take the shortest path around the color wheel.
+    Oklch,
+}
+
+/// A [`Color`] that animates using the given [`Interpolation`].
+///
+/// Any animation uses the interpolation of the color that's moving, i.e. the starting color.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct InterpolatedColor {
+    /// The current color.
+    pub color: Color,
+    /// The color space used to interpolate the color.
+    pub interpolation: Interpolation,
+}
+
+impl InterpolatedColor {
+    /// Creates a new [`InterpolatedColor`] from a `color` and its `interpolation`.
+    pub fn new(color: Color, interpolation: Interpolation) -> Self {
+        Self {
+            color,
+            interpolation,
+        }
+    }
+
+    /// The components of the color in the color space of the given `interpolation`.
+    fn components_in(&self, interpolation: Interpolation) -> [f32; 4] {
+        let Color { r, g, b, a } = self.color;
+        match interpolation {
+            Interpolation::Srgb => [r, g, b, a],
+            Interpolation::LinearRgb => [to_linear(r), to_linear(g), to_linear(b), a],
+            Interpolation::Oklch => {
+                let [l, c, h] = linear_to_oklch([to_linear(r), to_linear(g), to_linear(b)]);
+                [l, c, h, a]
+            }
+        }
+    }
+
+    /// Sets the color from components in the color space of the given `interpolation`.
+    fn set_components(&mut self, interpolation: Interpolation, [x, y, z, a]: [f32; 4]) {
+        let [r, g, b] = match interpolation {
+            Interpolation::Srgb => [x, y, z],
+            Interpolation::LinearRgb => [from_linear(x), from_linear(y), from_linear(z)],
+            Interpolation::Oklch => oklch_to_linear([x, y, z]).map(from_linear),
+        };
+
+        self.color = Color {
+            r: r.clamp(0.0, 1.0),
+            g: g.clamp(0.0, 1.0),
+            b: b.clamp(0.0, 1.0),
+            a: a.clamp(0.0, 1.0),
+        };
+    }
+}
+
+impl From<Color> for InterpolatedColor {
+    fn from(color: Color) -> Self {
+        Self::new(color, Interpolation::default())
+    }
+}
+
+impl From<InterpolatedColor> for Color {
+    fn from(color: InterpolatedColor) -> Self {
+        color.color
+    }
+}
+
+impl Animate for InterpolatedColor {
+    fn components() -> usize {
+        Color::components()
+    }
+
+    fn update(&mut self, components: &mut impl Iterator<Item = f32>) {
+        let mut values = self.components_in(self.interpolation);
+        let delta: [f32; 4] = std::array::from_fn(|_| components.next().unwrap());
+
+        if self.interpolation == Interpolation::Oklch && values[1] < ACHROMATIC_CHROMA {
+            // Gray colors move through the a/b plane, so they take on the target's hue directly.
+            let [lightness, a, b, alpha] = oklch_to_oklab(values);
+            let (a, b) = (a + delta[1], b + delta[2]);
+            values = [
+                lightness + delta[0],
+                a.hypot(b),
+                b.atan2(a),
+                alpha + delta[3],
+            ];
+        } else {
+            for (value, delta) in values.iter_mut().zip(delta) {
+                *value += delta;
+            }
+        }
+
+        if self.interpolation == Interpolation::Oklch {
+            values[2] = wrap_hue(values[2]);
+        }
+
+        self.set_components(self.interpolation, values);
+    }
+
+    /// The distance is measured in the color space of `end`, which is the color that moves
+    /// when it's updated by the distance.
+    fn distance_to(&self, end: &Self) -> Vec<f32> {
+        let interpolation = end.interpolation;
+        let mut start = self.components_in(interpolation);
+        let end = end.components_in(interpolation);
+
+        if interpolation == Interpolation::Oklch {
+            if end[1] < ACHROMATIC_CHROMA {
+                // Matches `update`, which moves gray colors through the a/b plane.
+                let start = oklch_to_oklab(start);
+                let end = oklch_to_oklab(end);
+                return start.iter().zip(end).map(|(s, e)| s - e).collect();
+            } else if start[1] < ACHROMATIC_CHROMA {
+                // Gray colors don't have a meaningful hue, so use the hue of the other color.
+                start[2] = end[2];
+            }
+        }
+
+        let mut distance: Vec<f32> = start.iter().zip(end).map(|(s, e)| s - e).collect();
+
+        if interpolation == Interpolation::Oklch {
+            distance[2] = -hue_difference(start[2], end[2]);
+        }
+
+        distance
+    }
+
+    fn lerp(&mut self, start: &Self, end: &Self, progress: f32) {
+        let interpolation = start.interpolation;
+        let mut from = start.components_in(interpolation);
+        let mut to = end.components_in(interpolation);
+
+        if interpolation == Interpolation::Oklch {
+            // Gray colors don't have a meaningful hue, so use the hue of the other color.
+            if from[1] < ACHROMATIC_CHROMA {
+                from[2] = to[2];
+            } else if to[1] < ACHROMATIC_CHROMA {
+                to[2] = from[2];
+            }
+            to[2] = from[2] + hue_difference(from[2], to[2]);
+        }
+
+        let mut values = [0.0; 4];
+        for (value, (from, to)) in values.iter_mut().zip(from.into_iter().zip(to)) {
+            value.lerp(&from, &to, progress);
+        }
+
+        self.interpolation = interpolation;
+        self.set_components(interpolation, values);
+    }
+}
+
+/// The chroma below which a color is considered gray.
+const ACHROMATIC_CHROMA: f32 = 1e-4;
+
+/// Converts OKLCH components with alpha to OKLab components with alpha.
+fn oklch_to_oklab([lightness, chroma, hue, alpha]: [f32; 4]) -> [f32; 4] {
+    [lightness, chroma * hue.cos(), chroma * hue.sin(), alpha]
+}
+
+/// The signed shortest angle to go from the `from` hue to the `to` hue.
+fn hue_difference(from: f32, to: f32) -> f32 {
+    wrap_hue(to - from)
+}
+
+/// Wraps a hue angle into the range [-π, π].
+fn wrap_hue(hue: f32) -> f32 {
+    (hue + PI).rem_euclid(TAU) - PI
+}
+
+/// Converts a gamma-encoded sRGB channel to linear light.
+fn to_linear(channel: f32) -> f32 {
+    if channel <= 0.04045 {
+        channel / 12.92
+    } else {
+        ((channel + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Converts a linear light channel to gamma-encoded sRGB.
+fn from_linear(channel: f32) -> f32 {
+    if channel <= 0.003_130_8 {
+        channel * 12.92
+    } else {
+        1.055 * channel.max(0.0).powf(1.0 / 2.4) - 0.055
+    }
+}
+
+/// Converts linear sRGB to OKLCH, with the hue in radians.
+///
+/// See <https://bottosson.github.io/posts/oklab/> for details on the conversion.
+fn linear_to_oklch([r, g, b]: [f32; 3]) -> [f32; 3] {
+    let l = (0.412_221_46 * r + 0.536_332_55 * g + 0.051_445_995 * b).cbrt();
+    let m = (0.211_903_5 * r + 0.680_699_5 * g + 0.107_396_96 * b).cbrt();
+    let s = (0.088_302_46 * r + 0.281_718_85 * g + 0.629_978_7 * b).cbrt();
+
+    let lightness = 0.210_454_26 * l + 0.793_617_8 * m - 0.004_072_047 * s;
+    let a = 1.977_998_5 * l - 2.428_592_2 * m + 0.450_593_7 * s;
+    let b = 0.025_904_037 * l + 0.782_771_77 * m - 0.808_675_77 * s;
+
+    [lightness, a.hypot(b), b.atan2(a)]
+}
+
+/// Converts OKLCH with the hue in radians to linear sRGB.
+fn oklch_to_linear([lightness, chroma, hue]: [f32; 3]) -> [f32; 3] {
+    let chroma = chroma.max(0.0);
+    let a = chroma * hue.cos();
+    let b = chroma * hue.sin();
+
+    let l = (lightness + 0.396_337_78 * a + 0.215_803_76 * b).powi(3);
+    let m = (lightness - 0.105_561_346 * a - 0.063_854_17 * b).powi(3);
+    let s = (lightness - 0.089_484_18 * a - 1.291_485_5 * b).powi(3);
+
+    [
+        4.076_741_7 * l - 3.307_711_6 * m + 0.230_969_94 * s,
+        -1.268_438 * l + 2.609_757_4 * m - 0.341_319_38 * s,
+        -0.004_196_086_3 * l - 0.703_418_6 * m + 1.707_614_7 * s,
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chroma_of(color: Color) -> f32 {
+        InterpolatedColor::from(color).components_in(Interpolation::Oklch)[1]
+    }
+
+    /// Converting to and from each color space should preserve the color.
+    #[test]
+    fn round_trip() {
+        let color = Color::from_rgba(0.2, 0.6, 0.9, 0.5);
+        for interpolation in [
+            Interpolation::Srgb,
+            Interpolation::LinearRgb,
+            Interpolation::Oklch,
+        ] {
+            let mut result = InterpolatedColor::new(color, interpolation);
+            result.set_components(interpolation, result.components_in(interpolation));
+            assert!((result.color.r - color.r).abs() < 1e-4);
+            assert!((result.color.g - color.g).abs() < 1e-4);
+            assert!((result.color.b - color.b).abs() < 1e-4);
+            assert_eq!(result.color.a, color.a);
+        }
+    }
+
+    /// Interpolating red to green in OKLCH should keep its saturation instead of graying out.
+    #[test]
+    fn oklch_keeps_saturation() {
+        let red = Color::from_rgb(1.0, 0.0, 0.0);
+        let green = Color::from_rgb(0.0, 1.0, 0.0);
+
+        let start = InterpolatedColor::new(red, Interpolation::Oklch);
+        let end = InterpolatedColor::new(green, Interpolation::Oklch);
+        let mut oklch = start;
+        oklch.lerp(&start, &end, 0.5);
+
+        let mut srgb = red;
+        srgb.lerp(&red, &green, 0.5);
+
+        assert!(chroma_of(oklch.color) > chroma_of(srgb));
+
+        // The midpoint should be a bright, fully saturated color.
+        let Color { r, g, b, .. } = oklch.color;
+        assert!(r.max(g).max(b) > 0.9);
+        assert!(r.min(g).min(b) < 0.05);
+    }
+
+    /// Hues should take the shortest path around the color wheel.
+    #[test]
+    fn hue_wraps_around() {
+        assert!((hue_difference(PI - 0.1, -PI + 0.1) - 0.2).abs() < 1e-5);
+        assert!((hue_difference(-PI + 0.1, PI - 0.1) + 0.2).abs() < 1e-5);
+    }
+
+    /// Springs and transitions should follow the same path, including when leaving gray and
+    /// when the two colors use different interpolations.
+    #[test]
+    fn update_matches_lerp() {
+        use crate::Spring;
+        use std::time::{Duration, Instant};
+
+        let gray = Color::from_rgb(0.5, 0.5, 0.5);
+        let red = Color::from_rgb(1.0, 0.0, 0.0);
+        let pairs = [
+            (Interpolation::Oklch, Interpolation::Oklch),
+            (Interpolation::Oklch, Interpolation::Srgb),
+            (Interpolation::Srgb, Interpolation::Oklch),
+            (Interpolation::LinearRgb, Interpolation::Oklch),
+        ];
+
+        for (start, end) in pairs {
+            for (from, to) in [(gray, red), (red, gray)] {
+                let from = InterpolatedColor::new(from, start);
+                let to = InterpolatedColor::new(to, end);
+
+                let mut lerped = from;
+                lerped.lerp(&from, &to, 0.3);
+                let mut updated = from;
+                updated.update(&mut to.distance_to(&from).into_iter().map(|d| d * 0.3));
+
+                for (a, b) in [
+                    (lerped.color.r, updated.color.r),
+                    (lerped.color.g, updated.color.g),
+                    (lerped.color.b, updated.color.b),
+                ] {
+                    assert!((a - b).abs() < 1e-3, "{lerped:?} != {updated:?}");
+                }
+            }
+        }
+
+        // A spring leaving gray should keep the hue of its target the whole way.
+        let from = InterpolatedColor::new(gray, Interpolation::Oklch);
+        let to = InterpolatedColor::new(red, Interpolation::Oklch);
+        let hue = to.components_in(Interpolation::Oklch)[2];
+        let mut spring = Spring::new(from).to(to);
+        let mut now = Instant::now();
+        while spring.has_energy() {
+            now += Duration::from_millis(16);
+            spring.tick(now);
+            let [_, chroma, value_hue, _] = spring.value().components_in(Interpolation::Oklch);
+            if chroma > 0.01 {
+                assert!(hue_difference(hue, value_hue).abs() < 1e-2);
+            }
+        }
+        assert_eq!(*spring.value(), to);
+    }
+
+    /// Updating by the distance to a target should reach that target.
+    #[test]
+    fn update_reaches_target() {
+        let start = InterpolatedColor::new(Color::from_rgb(1.0, 0.0, 0.0), Interpolation::Oklch);
+        let end = InterpolatedColor::new(Color::from_rgb(0.0, 0.0, 1.0), Interpolation::Oklch);
+        let mut color = start;
+        let mut components = end.distance_to(&start).into_iter();
+        color.update(&mut components);
+
+        assert!((color.color.r - end.color.r).abs() < 1e-3);
+        assert!((color.color.g - end.color.g).abs() < 1e-3);
+        assert!((color.color.b - end.color.b).abs() < 1e-3);
+    }
+}
@@ -51,6 +51,29 @@ pub enum Event<T> {
     SettleAt(T),
 }
 
+impl<T> Event<T> {
+    /// Creates a [`Event::Tick`] event at the current time.
+    pub fn tick() -> Self {
+        Event::Tick(Instant::now())
+    }
+
+    /// Creates a [`Event::Target`] event that moves towards the given `target`.
+    pub fn target(target: T) -> Self {
+        Event::Target(target)
+    }
+
+    /// Maps the value of a [`Event::Target`] or [`Event::SettleAt`] event with `f`, passing
+    /// other events through unchanged, e.g. to forward events to a nested component.
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> Event<U> {
+        match self {
+            Event::Tick(now) => Event::Tick(now),
+            Event::Target(target) => Event::Target(f(target)),
+            Event::Settle => Event::Settle,
+            Event::SettleAt(target) => Event::SettleAt(f(target)),
+        }
+    }
+}
+
 // Impl `Copy` for `Event` when `T` is `Copy`.
 impl<T> Copy for Event<T> where T: Copy {}
 
@@ -79,6 +102,22 @@ mod tests {
         assert!(matches!(update, Event::Target(5.0)));
     }
 
+    /// [`Event::tick`] should create a tick event at the current time.
+    #[test]
+    fn tick_uses_current_time() {
+        let before = Instant::now();
+        let Event::<f32>::Tick(now) = Event::tick() else {
+            panic!("expected a tick event");
+        };
+        assert!(now >= before && now <= Instant::now());
+    }
+
+    /// [`Event::target`] should create a target event with the given value.
+    #[test]
+    fn target_helper() {
+        assert_eq!(Event::target(5.0), Event::Target(5.0));
+    }
+
     /// [`Event`] should implement `Copy` when `T` does.
     #[test]
     fn copy_impl() {
@@ -86,4 +125,22 @@ mod tests {
         let copy = update;
         assert_eq!(update, copy);
     }
+
+    /// [`Event::map`] should map values and pass other events through.
+    #[test]
+    fn map() {
+        let to_string = |value: f32| value.to_string();
+        assert_eq!(
+            Event::Target(1.0).map(to_string),
+            Event::Target(String::from("1"))
+        );
+        assert_eq!(
+            Event::SettleAt(2.5).map(to_string),
+            Event::SettleAt(String::from("2.5"))
+        );
+        assert_eq!(Event::Settle.map(to_string), Event::Settle);
+
+        let now = Instant::now();
+        assert_eq!(Event::Tick(now).map(to_string), Event::Tick(now));
+    }
 }
@@ -0,0 +1,114 @@
+//! Animatable indices for sliding between discrete positions.
+//!
+//! Discrete states like the selected segment of a segmented control can't be animated directly,
+//! but their index can. An [`AnimatedIndex`] animates an index as a fractional position, so a
+//! sliding indicator can be drawn between two segments while it moves:
+//!
+//! ```rust
+//! # use iced_anim::{index::AnimatedIndex, Animate};
+//! let start = AnimatedIndex::new(0);
+//! let end = AnimatedIndex::new(2);
+//!
+//! let mut selected = start;
+//! selected.lerp(&start, &end, 0.75);
+//!
+//! // The indicator is halfway between the second and third segments.
+//! assert_eq!(selected.floor(), 1);
+//! assert_eq!(selected.frac(), 0.5);
+//! ```
+use crate::Animate;
+
+/// An index that animates through the fractional positions between discrete indices.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct AnimatedIndex {
+    /// The current position, which is a whole number when settled on an index.
+    position: f32,
+}
+
+impl AnimatedIndex {
+    /// Creates a new [`AnimatedIndex`] at the given `index`.
+    pub fn new(index: usize) -> Self {
+        Self {
+            position: index as f32,
+        }
+    }
+
+    /// The current fractional position of the index.
+    pub fn position(&self) -> f32 {
+        self.position
+    }
+
+    /// The index at or before the current position.
+    pub fn floor(&self) -> usize {
+        self.position.floor() as usize
+    }
+
+    /// How far the current position is between [`AnimatedIndex::floor`] and the next index,
+    /// in the range [0.0, 1.0).
+    pub fn frac(&self) -> f32 {
+        self.position.fract()
+    }
+
+    /// The index nearest to the current position.
+    pub fn nearest(&self) -> usize {
+        self.position.round() as usize
+    }
+}
+
+impl From<usize> for AnimatedIndex {
+    fn from(index: usize) -> Self {
+        Self::new(index)
+    }
+}
+
+impl Animate for AnimatedIndex {
+    fn components() -> usize {
+        f32::components()
+    }
+
+    fn update(&mut self, components: &mut impl Iterator<Item = f32>) {
+        self.position.update(components);
+        // Indices can't be negative, e.g. when a spring overshoots the first index.
+        self.position = self.position.max(0.0);
+    }
+
+    fn distance_to(&self, end: &Self) -> Vec<f32> {
+        self.position.distance_to(&end.position)
+    }
+
+    fn lerp(&mut self, start: &Self, end: &Self, progress: f32) {
+        self.position.lerp(&start.position, &end.position, progress);
+        self.position = self.position.max(0.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The midpoint between two indices should be reported as a fractional position.
+    #[test]
+    fn midpoint() {
+        let start = AnimatedIndex::new(0);
+        let end = AnimatedIndex::new(2);
+        let mut index = start;
+
+        index.lerp(&start, &end, 0.5);
+        assert_eq!(index.position(), 1.0);
+        assert_eq!(index.floor(), 1);
+        assert_eq!(index.frac(), 0.0);
+
+        index.lerp(&start, &end, 0.25);
+        assert_eq!(index.floor(), 0);
+        assert_eq!(index.frac(), 0.5);
+        assert_eq!(index.nearest(), 1);
+    }
+
+    /// Overshooting below the first index should stop at zero.
+    #[test]
+    fn never_negative() {
+        let mut index = AnimatedIndex::new(1);
+        index.update(&mut [-2.0].into_iter());
+        assert_eq!(index.position(), 0.0);
+    }
+}
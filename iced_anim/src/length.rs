@@ -0,0 +1,317 @@
+//! Animatable lengths that can move into and out of [`Length::Fill`].
+//!
+//! [`Length::Fill`] doesn't have a numeric value to interpolate, so an [`AnimatedLength`] keeps
+//! track of the space that a fill would take up. Use it with
+//! [`AnimationBuilder::resolve_target`](crate::AnimationBuilder::resolve_target) to measure the
+//! available space during layout, e.g.
+//!
+//! ```rust
+//! # use iced_widget::container;
+//! # use iced_anim::{AnimationBuilder, length::AnimatedLength};
+//! # type Element<'a, Message> = iced_core::Element<'a, Message, iced_core::Theme, iced_widget::Renderer>;
+//! # #[derive(Clone)]
+//! # enum Message {}
+//! fn sidebar<'a>(is_open: bool) -> Element<'a, Message> {
+//!     let width = if is_open {
+//!         AnimatedLength::fill()
+//!     } else {
+//!         AnimatedLength::fixed(0.0)
+//!     };
+//!
+//!     AnimationBuilder::new(width, |width| container("Sidebar").width(width.length()).into())
+//!         .resolve_target(|width, available| width.resolve(available.width))
+//!         .animates_layout(true)
+//!         .into()
+//! }
+//! ```
+use crate::Animate;
+use iced_core::{Length, Size};
+
+/// A length that can be animated between fixed values and [`Length::Fill`].
+///
+/// While animating, the length is always a fixed number of pixels. A length that fills the
+/// available space animates towards the measured size of that space, and then becomes a true
+/// [`Length::Fill`] once the animation settles.
+///
+/// Two filling lengths are considered equal regardless of their measured size, so changes to
+/// the available space don't restart an animation.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AnimatedLength {
+    /// The size of the length in pixels, which is the measured space when filling.
+    pixels: f32,
+    /// Whether the length fills the available space.
+    fill: bool,
+}
+
+impl AnimatedLength {
+    /// Creates a new [`AnimatedLength`] with a fixed number of `pixels`.
+    pub fn fixed(pixels: f32) -> Self {
+        Self {
+            pixels,
+            fill: false,
+        }
+    }
+
+    /// Creates a new [`AnimatedLength`] that fills the available space.
+    ///
+    /// The size of the available space is unknown until it's measured with
+    /// [`AnimatedLength::resolve`], which generally happens during layout.
+    pub fn fill() -> Self {
+        Self {
+            pixels: 0.0,
+            fill: true,
+        }
+    }
+
+    /// Measures a filling length against the `available` space, leaving fixed lengths as-is.
+    ///
+    /// Infinite space can't be animated towards, so the previous measurement is kept instead.
+    pub fn resolve(mut self, available: f32) -> Self {
+        if self.fill && available.is_finite() {
+            self.pixels = available;
+        }
+
+        self
+    }
+
+    /// The current size of the length in pixels.
+    pub fn pixels(&self) -> f32 {
+        self.pixels
+    }
+
+    /// Whether this length fills the available space.
+    pub fn is_fill(&self) -> bool {
+        self.fill
+    }
+
+    /// The [`Length`] to use in a widget.
+    pub fn length(&self) -> Length {
+        if self.fill {
+            Length::Fill
+        } else {
+            Length::Fixed(self.pixels)
+        }
+    }
+}
+
+impl PartialEq for AnimatedLength {
+    fn eq(&self, other: &Self) -> bool {
+        match (self.fill, other.fill) {
+            (true, true) => true,
+            (false, false) => self.pixels == other.pixels,
+            _ => false,
+        }
+    }
+}
+
+impl From<f32> for AnimatedLength {
+    fn from(pixels: f32) -> Self {
+        Self::fixed(pixels)
+    }
+}
+
+impl Animate for AnimatedLength {
+    fn components() -> usize {
+        f32::components()
+    }
+
+    fn update(&mut self, components: &mut impl Iterator<Item = f32>) {
+        let delta = components.next().unwrap();
+        if delta != 0.0 {
+            // Moving lengths are always fixed until they settle at their target.
+            self.fill = false;
+            self.pixels = (self.pixels + delta).max(0.0);
+        }
+    }
+
+    fn distance_to(&self, end: &Self) -> Vec<f32> {
+        self.pixels.distance_to(&end.pixels)
+    }
+
+    fn lerp(&mut self, start: &Self, end: &Self, progress: f32) {
+        self.pixels.lerp(&start.pixels, &end.pixels, progress);
+        self.fill = progress >= 1.0 && end.fill;
+    }
+}
+
+/// A size with mixed [`Length`]s that animates only its fixed dimensions, e.g. a container
+/// whose width animates in pixels while its height stays [`Length::Shrink`].
+///
+/// A dimension only animates when it's [`Length::Fixed`] at both the start and the end. Other
+/// dimensions, like [`Length::Shrink`] or [`Length::Fill`], can't be interpolated, so they
+/// switch to the target once the animation settles. Use an [`AnimatedLength`] for a dimension
+/// that animates into and out of [`Length::Fill`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AnimatedSize {
+    /// The current width and height.
+    size: Size<Length>,
+}
+
+impl AnimatedSize {
+    /// Creates a new [`AnimatedSize`] from a `width` and `height`.
+    pub fn new(width: impl Into<Length>, height: impl Into<Length>) -> Self {
+        Self {
+            size: Size::new(width.into(), height.into()),
+        }
+    }
+
+    /// The current width of the size.
+    pub fn width(&self) -> Length {
+        self.size.width
+    }
+
+    /// The current height of the size.
+    pub fn height(&self) -> Length {
+        self.size.height
+    }
+
+    /// The [`Size`] to use in a widget.
+    pub fn size(&self) -> Size<Length> {
+        self.size
+    }
+}
+
+impl From<Size<Length>> for AnimatedSize {
+    fn from(size: Size<Length>) -> Self {
+        Self { size }
+    }
+}
+
+impl Animate for AnimatedSize {
+    fn components() -> usize {
+        2 * f32::components()
+    }
+
+    fn update(&mut self, components: &mut impl Iterator<Item = f32>) {
+        for length in [&mut self.size.width, &mut self.size.height] {
+            let delta = components.next().unwrap();
+            if let Length::Fixed(pixels) = length {
+                *pixels = (*pixels + delta).max(0.0);
+            }
+        }
+    }
+
+    fn distance_to(&self, end: &Self) -> Vec<f32> {
+        [
+            (self.size.width, end.size.width),
+            (self.size.height, end.size.height),
+        ]
+        .into_iter()
+        .map(|lengths| match lengths {
+            (Length::Fixed(start), Length::Fixed(end)) => start - end,
+            _ => 0.0,
+        })
+        .collect()
+    }
+
+    fn lerp(&mut self, start: &Self, end: &Self, progress: f32) {
+        for (length, start, end) in [
+            (&mut self.size.width, start.size.width, end.size.width),
+            (&mut self.size.height, start.size.height, end.size.height),
+        ] {
+            *length = match (start, end) {
+                (Length::Fixed(start), Length::Fixed(end)) => {
+                    let mut pixels = start;
+                    pixels.lerp(&start, &end, progress);
+                    Length::Fixed(pixels)
+                }
+                _ if progress >= 1.0 => end,
+                _ => start,
+            };
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Only filling lengths should take on the available space.
+    #[test]
+    fn resolve() {
+        assert_eq!(AnimatedLength::fill().resolve(300.0).pixels(), 300.0);
+        assert_eq!(AnimatedLength::fixed(50.0).resolve(300.0).pixels(), 50.0);
+        assert_eq!(AnimatedLength::fill().resolve(f32::INFINITY).pixels(), 0.0);
+    }
+
+    /// Lengths should only be a true fill once they've reached a filling target.
+    #[test]
+    fn lerp_towards_fill() {
+        let start = AnimatedLength::fixed(0.0);
+        let end = AnimatedLength::fill().resolve(200.0);
+        let mut length = start;
+
+        length.lerp(&start, &end, 0.5);
+        assert_eq!(length.length(), Length::Fixed(100.0));
+
+        length.lerp(&start, &end, 1.0);
+        assert_eq!(length.length(), Length::Fill);
+    }
+
+    /// Only fixed dimensions should animate, while others hold until the animation settles.
+    #[test]
+    fn animated_size() {
+        use crate::{Easing, Transition};
+        use std::time::Duration;
+
+        let start = AnimatedSize::new(100.0, Length::Shrink);
+        let end = AnimatedSize::new(200.0, Length::Shrink);
+        let mut transition = Transition::new(start).to(end).with_easing(Easing::LINEAR);
+        transition.advance(Duration::from_millis(250));
+        assert_eq!(transition.value().width(), Length::Fixed(150.0));
+        assert_eq!(transition.value().height(), Length::Shrink);
+
+        let mut size = start;
+        let target = AnimatedSize::new(Length::Fill, Length::Fill);
+        size.lerp(&start, &target, 0.5);
+        assert_eq!(size, start);
+        size.lerp(&start, &target, 1.0);
+        assert_eq!(size, target);
+        assert_eq!(start.distance_to(&target), vec![0.0, 0.0]);
+    }
+
+    /// Springs and per-component curves should move fixed dimensions towards the target.
+    #[test]
+    fn animated_size_update() {
+        use crate::{transition::Curve, Easing, Spring, Transition};
+        use std::time::{Duration, Instant};
+
+        let start = AnimatedSize::new(100.0, Length::Shrink);
+        let end = AnimatedSize::new(200.0, Length::Shrink);
+        crate::animate::assert_consistent(&start, &end);
+
+        let mut transition = Transition::new(start)
+            .to(end)
+            .with_easing(Easing::LINEAR)
+            .with_component_curves(vec![(0..2, Curve::Linear)]);
+        transition.advance(Duration::from_millis(250));
+        assert_eq!(transition.value().width(), Length::Fixed(150.0));
+        assert_eq!(transition.value().height(), Length::Shrink);
+
+        let mut spring = Spring::new(start).to(end);
+        let mut now = Instant::now();
+        for _ in 0..5 {
+            now += Duration::from_millis(16);
+            spring.tick(now);
+            let Length::Fixed(width) = spring.value().width() else {
+                panic!("the width should stay fixed");
+            };
+            assert!(width > 100.0 && width < 200.0);
+        }
+        while spring.has_energy() {
+            now += Duration::from_millis(16);
+            spring.tick(now);
+        }
+        assert_eq!(*spring.value(), end);
+    }
+
+    /// Filling lengths should be equal regardless of their measured size.
+    #[test]
+    fn fills_are_equal() {
+        assert_eq!(
+            AnimatedLength::fill(),
+            AnimatedLength::fill().resolve(100.0)
+        );
+        assert_ne!(AnimatedLength::fill(), AnimatedLength::fixed(0.0));
+    }
+}
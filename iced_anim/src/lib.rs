@@ -50,6 +50,11 @@
 //! The spring motion of an [`AnimationBuilder`] can be customized. There are some presets like
 //! [`spring::Motion::smooth`] and [`spring::Motion::bouncy`], but you can also create your own.
 //!
+//! ## Reduced motion
+//!
+//! Call [`set_reduced_motion`] to make every animation settle immediately, e.g. when the user
+//! has asked their operating system to reduce motion.
+//!
 //! ## Supported Iced versions
 //!
 //! This crate supports Iced 0.14 and newer.
@@ -58,18 +63,30 @@ pub mod animated;
 mod animated_state;
 pub mod animation;
 pub mod animation_builder;
+pub mod animation_map;
+pub mod animation_set;
+pub mod color;
 pub mod event;
+pub mod index;
+pub mod length;
+pub mod overshoot;
+mod reduced_motion;
+pub mod scalar;
 pub mod spring;
 pub mod transition;
 #[cfg(feature = "widgets")]
 pub mod widget;
+pub mod window;
 
 pub use animate::Animate;
 pub use animated::{Animated, AnimationType};
 pub use animated_state::AnimatedState;
 pub use animation::Animation;
 pub use animation_builder::*;
+pub use animation_map::AnimationMap;
+pub use animation_set::AnimationSet;
 pub use event::Event;
+pub use reduced_motion::{is_reduced_motion, set_reduced_motion};
 pub use spring::{Motion, Spring};
 pub use transition::{Easing, Transition};
 
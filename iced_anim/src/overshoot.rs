@@ -0,0 +1,130 @@
+//! Values that overshoot their target before settling, without using a spring.
+//!
+//! Springs overshoot naturally, but they're driven by physics rather than a fixed duration.
+//! Wrapping a value in an [`Overshoot`] bakes a "back" style overshoot into its interpolation,
+//! so any [`Transition`](crate::Transition) of it briefly passes the target and then settles:
+//!
+//! ```rust
+//! # use iced_anim::{overshoot::Overshoot, Easing, Transition};
+//! let mut scale = Transition::new(Overshoot::new(1.0)).with_easing(Easing::LINEAR);
+//! scale.set_target(Overshoot::new(1.5));
+//!
+//! // Read the inner value when drawing.
+//! let value: f32 = *scale.value().value();
+//! ```
+use crate::Animate;
+
+/// The default amount of overshoot, which is the standard "back" curve constant and overshoots
+/// by about 10%.
+const DEFAULT_AMOUNT: f32 = 1.70158;
+
+/// An animatable value that overshoots its target before settling when interpolated.
+///
+/// Interpolation follows an "ease out back" curve on top of the transition's own curve, so the
+/// value always ends exactly at the target. Updating by components, like a spring does, isn't
+/// affected.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Overshoot<T> {
+    /// The wrapped value.
+    value: T,
+    /// How far to overshoot, where `0.0` doesn't overshoot at all.
+    amount: f32,
+}
+
+impl<T> Overshoot<T> {
+    /// Creates a new [`Overshoot`] that overshoots by about 10% of the distance travelled.
+    pub fn new(value: T) -> Self {
+        Self {
+            value,
+            amount: DEFAULT_AMOUNT,
+        }
+    }
+
+    /// Sets how far to overshoot and returns the updated value, where `0.0` doesn't overshoot
+    /// and larger amounts overshoot further. Animations use the amount of the target value.
+    pub fn with_amount(mut self, amount: f32) -> Self {
+        self.amount = amount;
+        self
+    }
+
+    /// Returns a reference to the wrapped value.
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// Returns the wrapped value.
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T> From<T> for Overshoot<T> {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T> Animate for Overshoot<T>
+where
+    T: Animate,
+{
+    fn components() -> usize {
+        T::components()
+    }
+
+    fn update(&mut self, components: &mut impl Iterator<Item = f32>) {
+        self.value.update(components);
+    }
+
+    fn distance_to(&self, end: &Self) -> Vec<f32> {
+        self.value.distance_to(&end.value)
+    }
+
+    fn lerp(&mut self, start: &Self, end: &Self, progress: f32) {
+        // See <https://easings.net/#easeOutBack>.
+        let amount = end.amount;
+        let t = progress - 1.0;
+        let progress = 1.0 + (amount + 1.0) * t * t * t + amount * t * t;
+
+        self.value.lerp(&start.value, &end.value, progress);
+        self.amount = amount;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Easing, Transition};
+    use std::time::Duration;
+
+    /// Transitions of overshooting values should pass the target before settling on it.
+    #[test]
+    fn exceeds_target_before_settling() {
+        let mut transition = Transition::new(Overshoot::new(0.0))
+            .to(Overshoot::new(1.0))
+            .with_easing(Easing::LINEAR);
+
+        let frame = Duration::from_millis(10);
+        let mut max: f32 = 0.0;
+        while transition.is_animating() {
+            transition.advance(frame);
+            max = max.max(*transition.value().value());
+        }
+
+        assert!(max > 1.05);
+        assert_eq!(*transition.value().value(), 1.0);
+    }
+
+    /// Values without any overshoot should never pass the target.
+    #[test]
+    fn no_overshoot() {
+        let start = Overshoot::new(0.0).with_amount(0.0);
+        let end = Overshoot::new(1.0).with_amount(0.0);
+        let mut value = start;
+        for step in 0..=10 {
+            value.lerp(&start, &end, step as f32 / 10.0);
+            assert!(*value.value() <= 1.0);
+        }
+        assert_eq!(*value.value(), 1.0);
+    }
+}
@@ -0,0 +1,21 @@
+//! A global switch for reducing motion across all animations.
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Whether animations should settle immediately instead of animating.
+static REDUCED_MOTION: AtomicBool = AtomicBool::new(false);
+
+/// Sets whether all animations should use reduced motion, e.g. to respect the "reduce motion"
+/// accessibility setting of the operating system.
+///
+/// When enabled, transitions and springs settle at their target on their next tick regardless
+/// of their duration. This is stored atomically, so it can safely be changed from any thread
+/// and applies to every animation in the process.
+pub fn set_reduced_motion(enabled: bool) {
+    REDUCED_MOTION.store(enabled, Ordering::Relaxed);
+}
+
+/// Whether all animations are using reduced motion.
+/// See [`set_reduced_motion`] for more details.
+pub fn is_reduced_motion() -> bool {
+    REDUCED_MOTION.load(Ordering::Relaxed)
+}
@@ -0,0 +1,160 @@
+//! Animate any type that can be mapped to and from a single number.
+//!
+//! Types like enums with ordered variants can't be animated directly, but they can often be
+//! mapped to a number and back. A [`ScalarAnimated`] animates that number and reconstructs the
+//! value whenever it's read, which generalizes [`AnimatedIndex`](crate::index::AnimatedIndex)
+//! to arbitrary types:
+//!
+//! ```rust
+//! # use iced_anim::{scalar::ScalarAnimated, Animate};
+//! #[derive(Debug, PartialEq)]
+//! enum Volume {
+//!     Low,
+//!     High,
+//! }
+//!
+//! fn to_scalar(volume: &Volume) -> f32 {
+//!     match volume {
+//!         Volume::Low => 0.0,
+//!         Volume::High => 1.0,
+//!     }
+//! }
+//!
+//! fn from_scalar(scalar: f32) -> Volume {
+//!     if scalar < 0.5 { Volume::Low } else { Volume::High }
+//! }
+//!
+//! let start = ScalarAnimated::new(&Volume::Low, to_scalar, from_scalar);
+//! let end = start.with_value(&Volume::High);
+//!
+//! let mut volume = start;
+//! volume.lerp(&start, &end, 0.75);
+//! assert_eq!(volume.scalar(), 0.75);
+//! assert_eq!(volume.value(), Volume::High);
+//! ```
+use crate::Animate;
+
+/// A value that animates through a number it's mapped to, and is reconstructed when read.
+///
+/// Two values are equal when their numbers are equal. Any animation uses the mapping of the
+/// target value.
+#[derive(Debug)]
+pub struct ScalarAnimated<T> {
+    /// The current number that the value is mapped to.
+    scalar: f32,
+    /// Maps a value to its number.
+    to_scalar: fn(&T) -> f32,
+    /// Reconstructs a value from a number, which may be between the numbers of two values.
+    from_scalar: fn(f32) -> T,
+}
+
+impl<T> ScalarAnimated<T> {
+    /// Creates a new [`ScalarAnimated`] from a `value` and the functions that map it to and
+    /// from a number.
+    pub fn new(value: &T, to_scalar: fn(&T) -> f32, from_scalar: fn(f32) -> T) -> Self {
+        Self {
+            scalar: to_scalar(value),
+            to_scalar,
+            from_scalar,
+        }
+    }
+
+    /// Creates a new [`ScalarAnimated`] with the same mapping as this one for the given `value`,
+    /// e.g. to create a target to animate towards.
+    pub fn with_value(&self, value: &T) -> Self {
+        Self::new(value, self.to_scalar, self.from_scalar)
+    }
+
+    /// The current number that the value is mapped to, which may be between the numbers of two
+    /// values while animating.
+    pub fn scalar(&self) -> f32 {
+        self.scalar
+    }
+
+    /// Reconstructs the current value from its number.
+    pub fn value(&self) -> T {
+        (self.from_scalar)(self.scalar)
+    }
+}
+
+impl<T> Clone for ScalarAnimated<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for ScalarAnimated<T> {}
+
+impl<T> PartialEq for ScalarAnimated<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.scalar == other.scalar
+    }
+}
+
+impl<T> Animate for ScalarAnimated<T> {
+    fn components() -> usize {
+        f32::components()
+    }
+
+    fn update(&mut self, components: &mut impl Iterator<Item = f32>) {
+        self.scalar.update(components);
+    }
+
+    fn distance_to(&self, end: &Self) -> Vec<f32> {
+        self.scalar.distance_to(&end.scalar)
+    }
+
+    fn lerp(&mut self, start: &Self, end: &Self, progress: f32) {
+        self.scalar.lerp(&start.scalar, &end.scalar, progress);
+        self.to_scalar = end.to_scalar;
+        self.from_scalar = end.from_scalar;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{animated::DEFAULT_DURATION, Easing, Transition};
+
+    #[derive(Debug, PartialEq)]
+    enum Volume {
+        Low,
+        Medium,
+        High,
+    }
+
+    fn to_scalar(volume: &Volume) -> f32 {
+        match volume {
+            Volume::Low => 0.0,
+            Volume::Medium => 1.0,
+            Volume::High => 2.0,
+        }
+    }
+
+    fn from_scalar(scalar: f32) -> Volume {
+        match scalar.round() as i32 {
+            ..=0 => Volume::Low,
+            1 => Volume::Medium,
+            _ => Volume::High,
+        }
+    }
+
+    /// Animating between variants should pass through the fractional states between them.
+    #[test]
+    fn animate_enum() {
+        let low = ScalarAnimated::new(&Volume::Low, to_scalar, from_scalar);
+        let mut transition = Transition::new(low)
+            .to(low.with_value(&Volume::High))
+            .with_easing(Easing::LINEAR);
+
+        transition.advance(DEFAULT_DURATION / 4);
+        assert_eq!(transition.value().scalar(), 0.5);
+        assert_eq!(transition.value().value(), Volume::Medium);
+
+        transition.advance(DEFAULT_DURATION / 4);
+        assert_eq!(transition.value().scalar(), 1.0);
+
+        transition.advance(DEFAULT_DURATION);
+        assert_eq!(transition.value().value(), Volume::High);
+    }
+}
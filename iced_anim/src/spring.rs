@@ -7,7 +7,7 @@ use std::{
     time::{Duration, Instant},
 };
 
-use crate::{event::Event, Animate};
+use crate::{animate, event::Event, Animate};
 
 /// The minimum percent at which a spring is considered near its target.
 ///
@@ -43,10 +43,19 @@ pub struct Spring<T> {
     last_update: Instant,
     /// The current velocity components that make up this spring animation.
     velocity: Vec<f32>,
+    /// The movement of each component that the value couldn't represent in the last update,
+    /// e.g. a fraction of a codepoint for a `char`, which is carried over to the next update
+    /// so that small movements aren't lost.
+    remainder: Vec<f32>,
     /// The initial distance from the target when the animation was started or interrupted.
     /// This is used to help determine when the spring is near its target and is precomputed
     /// to avoid recalculating it every frame.
     initial_distance: Vec<f32>,
+    /// The percent of the initial distance within which the spring is considered at rest.
+    rest_epsilon: f32,
+    /// The percent of the initial distance per second below which the spring is considered
+    /// to have stopped moving.
+    velocity_epsilon: f32,
 }
 
 // Impls that don't require an `Animate` bound.
@@ -87,6 +96,23 @@ impl<T> Spring<T> {
         self.motion = motion;
         self
     }
+
+    /// Returns an updated spring that settles once it's within `epsilon` of its target, as a
+    /// percent of the distance it started from. Defaults to [`ESPILON`].
+    ///
+    /// Larger values settle sooner, which suits subtle animations like a blur, while smaller
+    /// values are more precise for large movements.
+    pub fn with_rest_epsilon(mut self, epsilon: f32) -> Self {
+        self.rest_epsilon = epsilon;
+        self
+    }
+
+    /// Returns an updated spring that settles once its velocity is below `epsilon`, as a percent
+    /// of the distance it started from per second. Defaults to [`ESPILON`].
+    pub fn with_velocity_epsilon(mut self, epsilon: f32) -> Self {
+        self.velocity_epsilon = epsilon;
+        self
+    }
 }
 
 impl<T> Spring<T>
@@ -104,7 +130,10 @@ where
             motion,
             last_update: Instant::now(),
             velocity: vec![0.0; T::components()],
+            remainder: vec![0.0; T::components()],
             initial_distance: vec![0.0; T::components()],
+            rest_epsilon: ESPILON,
+            velocity_epsilon: ESPILON,
         }
     }
 
@@ -158,11 +187,24 @@ where
             return;
         }
 
-        let dt = now.duration_since(self.last_update).min(MAX_DURATION);
+        let dt = now.duration_since(self.last_update);
         self.last_update = now;
+        self.advance(dt);
+    }
+
+    /// Moves the spring forward by the given `elapsed` time, without reading the clock.
+    ///
+    /// The elapsed time is capped at [`MAX_DURATION`] to keep the simulation stable. The time of
+    /// the last update isn't changed, so avoid mixing this with [`Spring::tick`].
+    pub fn advance(&mut self, elapsed: Duration) {
+        if !self.has_energy() {
+            return;
+        }
+
+        let dt = elapsed.min(MAX_DURATION);
 
         // End the animation if the spring is near the target wiht low velocity.
-        if self.is_near_end() {
+        if self.is_near_end() || crate::is_reduced_motion() {
             self.settle();
             return;
         }
@@ -172,13 +214,25 @@ where
             .target
             .distance_to(&self.value)
             .into_iter()
+            .zip(&self.remainder)
             .zip(self.velocity.iter().copied())
-            .map(|(d, v)| self.new_velocity(d, v, dt.as_secs_f32()))
+            .map(|((d, r), v)| self.new_velocity(d - r, v, dt.as_secs_f32()))
+            .collect();
+
+        let movement: Vec<f32> = velocity
+            .iter()
+            .zip(&self.remainder)
+            .map(|(v, r)| v * dt.as_secs_f32() + r)
             .collect();
+        let previous = self.value.clone();
+        self.value.update(&mut movement.iter().copied());
 
-        self.velocity.clone_from(&velocity);
-        let mut components = velocity.iter().map(|v| v * dt.as_secs_f32());
-        self.value.update(&mut components);
+        // Carry over any movement that the value couldn't represent, e.g. due to rounding.
+        let moved = self.value.distance_to(&previous);
+        for ((remainder, intended), moved) in self.remainder.iter_mut().zip(movement).zip(moved) {
+            *remainder = intended - moved;
+        }
+        self.velocity = velocity;
     }
 
     /// Gets the new velocity of the spring given the `displacement` and `velocity`.
@@ -192,9 +246,11 @@ where
     }
 
     /// Interrupts the existing animation and starts a new one with the `new_target`.
+    ///
+    /// Targets with NaN or infinite components, e.g. from dividing by zero, are ignored.
     pub fn set_target(&mut self, new_target: T) {
-        // Don't do anything if the target hasn't changed.
-        if self.target == new_target {
+        // Don't do anything if the target hasn't changed or can't be reached.
+        if self.target == new_target || !animate::is_finite(&new_target) {
             return;
         }
 
@@ -206,6 +262,7 @@ where
 
         self.target = new_target;
         self.initial_distance = self.value.distance_to(&self.target);
+        self.remainder = vec![0.0; T::components()];
     }
 
     /// Causes the spring to settle immediately at the target value,
@@ -215,6 +272,7 @@ where
         // even if the curve doesn't reach it or the animation implementation isn't correct.
         self.value = self.target.clone();
         self.velocity = vec![0.0; T::components()];
+        self.remainder = vec![0.0; T::components()];
     }
 
     /// Makes the spring value and target immediately settle at the given `target`.
@@ -222,6 +280,7 @@ where
         self.value = target.clone();
         self.target = target;
         self.velocity = vec![0.0; T::components()];
+        self.remainder = vec![0.0; T::components()];
     }
 
     /// Whether the spring is near the end of its animation.
@@ -234,6 +293,8 @@ where
                 .value
                 .distance_to(&self.target)
                 .iter()
+                .zip(&self.remainder)
+                .map(|(d, r)| d + r)
                 .zip(&self.initial_distance)
                 .zip(&self.velocity)
                 .all(|((d, i), v)| match i {
@@ -241,7 +302,7 @@ where
                     _ => {
                         let d_percent = (d / i).abs();
                         let v_percent = (v / i).abs();
-                        d_percent <= ESPILON && v_percent <= ESPILON
+                        d_percent <= self.rest_epsilon && v_percent <= self.velocity_epsilon
                     }
                 })
     }
@@ -289,6 +350,37 @@ mod tests {
         assert!(spring.has_energy());
     }
 
+    /// Smaller epsilons should make the spring animate for longer before settling.
+    #[test]
+    fn rest_epsilon() {
+        let ticks = |epsilon: f32| {
+            let mut spring = Spring::new(0.0)
+                .to(100.0)
+                .with_rest_epsilon(epsilon)
+                .with_velocity_epsilon(epsilon);
+            let mut now = spring.last_update();
+            let mut ticks = 0;
+            while spring.has_energy() {
+                now += Duration::from_millis(16);
+                spring.tick(now);
+                ticks += 1;
+            }
+            ticks
+        };
+
+        assert!(ticks(0.0001) > ticks(ESPILON));
+        assert!(ticks(ESPILON) > ticks(0.1));
+    }
+
+    /// Springs should ignore non-finite targets.
+    #[test]
+    fn non_finite_target() {
+        let mut spring = Spring::new(0.0);
+        spring.set_target(f32::NAN);
+        assert_eq!(spring.target(), &0.0);
+        assert!(!spring.has_energy());
+    }
+
     /// [`Spring::settle_at`] should set the spring's value and target to the given value
     /// and bring all velocity components to zero.
     #[test]
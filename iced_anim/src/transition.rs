@@ -1,14 +1,45 @@
 //! A type of animation that transitions between two values by following a curve.
+mod axes;
 pub mod bezier;
 pub mod curve;
 mod easing;
+mod mapped;
+mod piecewise;
 mod progress;
+mod sequence;
 
-use crate::{Animate, Event};
+use crate::{
+    animate::{self, AnimateError},
+    Animate, Event,
+};
+pub use axes::Transition2D;
 pub use curve::Curve;
 pub use easing::Easing;
-pub use progress::Progress;
-use std::time::{Duration, Instant};
+pub use mapped::MappedTransition;
+pub use piecewise::PiecewiseCurve;
+pub use progress::{Direction, Progress};
+pub use sequence::Sequence;
+use std::{
+    ops::Range,
+    time::{Duration, Instant},
+};
+
+/// The step used to estimate the slope of a curve when measuring speed.
+const SLOPE_STEP: f32 = 1e-3;
+
+/// The longest duration that [`Transition::retarget`] picks, as a multiple of the easing's
+/// duration, so retargeting on a flat part of a curve doesn't crawl to the new target.
+const MAX_RETARGET_SCALE: f64 = 4.0;
+
+/// The default duration of a frame for [`Transition::tick_frames`], which is 60 frames per second.
+const DEFAULT_FRAME_DURATION: Duration = Duration::from_nanos(1_000_000_000 / 60);
+
+/// The default shortest duration that a transition animates for. Shorter transitions settle
+/// immediately, since they'd finish within a single frame anyway.
+const DEFAULT_MIN_DURATION: Duration = Duration::from_millis(1);
+
+/// The number of points sampled along a transition's path when normalizing its speed.
+const PATH_SAMPLES: usize = 64;
 
 /// A type of animation that transitions between two values.
 #[derive(Debug, Clone, PartialEq)]
@@ -25,6 +56,42 @@ pub struct Transition<T> {
     progress: Progress,
     /// The time at which the transition was last updated.
     last_update: Instant,
+    /// Curves applied to specific ranges of components instead of the easing's curve.
+    /// Components outside of these ranges will use the curve from the `easing`.
+    component_curves: Vec<(Range<usize>, Curve)>,
+    /// The distance between the initial and target values, computed when the target changes
+    /// to avoid recalculating it every frame.
+    distance: Vec<f32>,
+    /// Whether the value moves along its path at a speed set by the easing's curve,
+    /// rather than each component following its own curve over time.
+    normalize_speed: bool,
+    /// The assumed duration of a single frame when ticking by a number of frames.
+    frame_duration: Duration,
+    /// A multiplier applied to elapsed time, e.g. `0.5` for half speed.
+    speed: f32,
+    /// An extra multiplier applied to elapsed time while the transition is reversing.
+    reverse_speed: f32,
+    /// Whether reversing uses the mirrored curve, so the return trip eases like the forward one.
+    mirror_reverse: bool,
+    /// Scales the duration by the distance to each new target, if set.
+    duration_per_distance: Option<DurationPerDistance>,
+    /// A multiplier applied to the duration so grouped transitions don't move in lockstep.
+    jitter: f32,
+    /// Transitions with a shorter duration settle immediately instead of animating.
+    min_duration: Duration,
+    /// The duration in seconds, including jitter, cached to avoid converting it every frame.
+    duration_secs: f32,
+}
+
+/// How to scale the duration of a transition by the distance it travels.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct DurationPerDistance {
+    /// The duration for each unit of distance.
+    per_unit: Duration,
+    /// The shortest duration to use, e.g. for tiny changes.
+    min: Duration,
+    /// The longest duration to use, e.g. for huge changes.
+    max: Duration,
 }
 
 impl<T> Transition<T>
@@ -40,18 +107,281 @@ where
             easing: Easing::default(),
             progress: Progress::default(),
             last_update: Instant::now(),
+            component_curves: Vec::new(),
+            distance: vec![0.0; T::components()],
+            normalize_speed: false,
+            frame_duration: DEFAULT_FRAME_DURATION,
+            speed: 1.0,
+            reverse_speed: 1.0,
+            mirror_reverse: false,
+            duration_per_distance: None,
+            jitter: 1.0,
+            min_duration: DEFAULT_MIN_DURATION,
+            duration_secs: Easing::default().duration.as_secs_f32(),
+        }
+    }
+
+    /// Starts the transition from the given `initial` value and returns the updated transition,
+    /// e.g. to animate an entrance from some "from" value to the value the transition was
+    /// created with.
+    ///
+    /// The target is left unchanged and the transition starts animating towards it from the
+    /// beginning. If `initial` is the same as the target, the transition stays settled.
+    pub fn with_initial(mut self, initial: T) -> Self {
+        if initial != self.target {
+            self.value = initial.clone();
+            self.initial = initial;
+            self.progress = Progress::Forward(0.0);
+            self.distance = self.initial.distance_to(&self.target);
+            self.last_update = Instant::now();
         }
+
+        self
+    }
+
+    /// Sets the progress of the transition and returns the updated transition, e.g. to resume a
+    /// transition that was partway through when its state was saved.
+    ///
+    /// The current value is recomputed from the initial and target values at that progress.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the progress isn't in the range [0.0, 1.0].
+    pub fn with_progress(mut self, progress: Progress) -> Self {
+        assert!(
+            (0.0..=1.0).contains(&progress.progress()),
+            "transition progress must be in the range [0.0, 1.0], found {progress:?}"
+        );
+        self.progress = progress;
+        self.interpolate();
+        self
     }
 
     /// Sets the easing to use for the transition and returns the updated transition.
     pub fn with_easing(mut self, easing: Easing) -> Self {
-        self.easing = easing;
+        self.set_easing(easing);
         self
     }
 
     /// Sets the easing of the transition.
     pub fn set_easing(&mut self, easing: Easing) {
         self.easing = easing;
+        self.refresh_duration();
+    }
+
+    /// Sets the duration of the transition's easing and returns the updated transition.
+    pub fn with_duration(mut self, duration: Duration) -> Self {
+        self.set_duration(duration);
+        self
+    }
+
+    /// Sets the duration of the transition's easing.
+    pub fn set_duration(&mut self, duration: Duration) {
+        self.easing.duration = duration;
+        self.refresh_duration();
+    }
+
+    /// Sets the curve of the transition's easing.
+    ///
+    /// A transition that's in progress continues from its current value, following the new
+    /// curve for the rest of the transition.
+    pub fn set_curve(&mut self, curve: Curve) {
+        if self.is_animating() {
+            let position = self.eased(self.easing.curve, self.progress.value());
+            self.easing.curve = curve;
+            self.seek_eased(position);
+        } else {
+            self.easing.curve = curve;
+        }
+    }
+
+    /// Sets the curves to use for specific ranges of components and returns the updated transition.
+    ///
+    /// Each range refers to component indices in the same order as [`Animate::distance_to`],
+    /// e.g. `0..1` is the first `f32` of a `(f32, f32)`. Components not covered by any range will
+    /// follow the curve of the transition's [`Easing`]. If ranges overlap, the first one is used.
+    pub fn with_component_curves(mut self, curves: Vec<(Range<usize>, Curve)>) -> Self {
+        self.component_curves = curves;
+        self
+    }
+
+    /// Sets the curves to use for specific ranges of components.
+    /// See [`Transition::with_component_curves`] for more details.
+    pub fn set_component_curves(&mut self, curves: Vec<(Range<usize>, Curve)>) {
+        self.component_curves = curves;
+    }
+
+    /// Sets whether to normalize the speed of the transition and returns the updated transition.
+    ///
+    /// By default, each component follows its curve over time, so when components use
+    /// different [component curves](Transition::with_component_curves), the combined value
+    /// speeds up and slows down depending on which component is moving fastest. With normalized
+    /// speed, the component curves only shape the path taken by the value, and the value moves
+    /// along that path according to the curve of the transition's [`Easing`]. Each component is
+    /// weighted by how far it moves, so all components still arrive together, but e.g. a
+    /// [`Curve::Linear`] easing moves the combined value at a constant speed.
+    pub fn with_normalized_speed(mut self, normalize_speed: bool) -> Self {
+        self.normalize_speed = normalize_speed;
+        self
+    }
+
+    /// Sets whether to normalize the speed of the transition.
+    /// See [`Transition::with_normalized_speed`] for more details.
+    pub fn set_normalized_speed(&mut self, normalize_speed: bool) {
+        self.normalize_speed = normalize_speed;
+    }
+
+    /// Sets the assumed duration of a frame for [`Transition::tick_frames`] and returns the
+    /// updated transition. This defaults to 60 frames per second.
+    pub fn with_frame_duration(mut self, frame_duration: Duration) -> Self {
+        self.frame_duration = frame_duration;
+        self
+    }
+
+    /// Sets the assumed duration of a frame for [`Transition::tick_frames`].
+    pub fn set_frame_duration(&mut self, frame_duration: Duration) {
+        self.frame_duration = frame_duration;
+    }
+
+    /// Sets the speed multiplier of the transition and returns the updated transition.
+    ///
+    /// Elapsed time is scaled by the multiplier, so `0.5` plays the transition at half speed and
+    /// `2.0` plays it at double speed without changing its duration. This is mostly useful for
+    /// slowing animations down while debugging them.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `multiplier` isn't a positive, finite number.
+    pub fn with_speed(mut self, multiplier: f32) -> Self {
+        self.set_speed(multiplier);
+        self
+    }
+
+    /// Sets the speed multiplier of the transition.
+    /// See [`Transition::with_speed`] for more details.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `multiplier` isn't a positive, finite number.
+    pub fn set_speed(&mut self, multiplier: f32) {
+        assert!(
+            multiplier > 0.0 && multiplier.is_finite(),
+            "the speed of a transition must be positive and finite, found {multiplier}"
+        );
+        self.speed = multiplier;
+    }
+
+    /// Sets an extra speed multiplier used while the transition is reversing and returns the
+    /// updated transition, e.g. `2.0` to dismiss something twice as fast as it entered.
+    ///
+    /// This applies on top of the [speed multiplier](Transition::with_speed) and defaults to
+    /// `1.0`, so both directions take the same amount of time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `multiplier` isn't a positive, finite number.
+    pub fn with_reverse_speed(mut self, multiplier: f32) -> Self {
+        self.set_reverse_speed(multiplier);
+        self
+    }
+
+    /// Sets the extra speed multiplier used while the transition is reversing.
+    /// See [`Transition::with_reverse_speed`] for more details.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `multiplier` isn't a positive, finite number.
+    pub fn set_reverse_speed(&mut self, multiplier: f32) {
+        assert!(
+            multiplier > 0.0 && multiplier.is_finite(),
+            "the reverse speed of a transition must be positive and finite, found {multiplier}"
+        );
+        self.reverse_speed = multiplier;
+    }
+
+    /// Sets whether the transition is reversible and returns the updated transition.
+    ///
+    /// This is the same as setting [`Easing::reversible`] on the transition's easing. A reversible
+    /// transition that's interrupted by its initial value retraces its path back, while one that
+    /// isn't reversible restarts its curve from the current value towards the initial value.
+    pub fn with_reversible(mut self, reversible: bool) -> Self {
+        self.easing.reversible = reversible;
+        self
+    }
+
+    /// Sets whether the transition is reversible.
+    /// See [`Transition::with_reversible`] for more details.
+    pub fn set_reversible(&mut self, reversible: bool) {
+        self.easing.reversible = reversible;
+    }
+
+    /// Sets whether reversing follows the mirrored curve and returns the updated transition.
+    ///
+    /// By default, a reversible transition retraces its curve backwards when it reverses, so an
+    /// asymmetric curve like [`Curve::EaseIn`] that accelerates into the target will decelerate
+    /// into the initial value on the way back. With a mirrored reverse, the return trip follows
+    /// the curve mirrored from the target's end, e.g. [`Curve::EaseOut`] instead of
+    /// [`Curve::EaseIn`], so it accelerates into the initial value the same way. The progress is
+    /// adjusted when reversing so that the value doesn't jump.
+    pub fn with_mirrored_reverse(mut self, mirror_reverse: bool) -> Self {
+        self.mirror_reverse = mirror_reverse;
+        self
+    }
+
+    /// Sets whether reversing follows the mirrored curve.
+    /// See [`Transition::with_mirrored_reverse`] for more details.
+    pub fn set_mirrored_reverse(&mut self, mirror_reverse: bool) {
+        self.mirror_reverse = mirror_reverse;
+    }
+
+    /// Scales the duration of the transition by the distance to each new target and returns the
+    /// updated transition, so small changes are quick and large ones take longer.
+    ///
+    /// Whenever the target changes, the duration becomes `per_unit` for each unit of distance
+    /// between the current value and the new target, clamped between `min` and `max`. The
+    /// distance is the euclidean length of the components from [`Animate::distance_to`].
+    pub fn with_duration_per_distance(
+        mut self,
+        per_unit: Duration,
+        min: Duration,
+        max: Duration,
+    ) -> Self {
+        self.duration_per_distance = Some(DurationPerDistance { per_unit, min, max });
+        self
+    }
+
+    /// Sets the shortest duration that the transition animates for and returns the updated
+    /// transition. Transitions with a shorter duration settle on the next update instead, which
+    /// avoids huge jumps in progress and precision issues with tiny durations. Defaults to 1ms.
+    pub fn with_min_duration(mut self, min_duration: Duration) -> Self {
+        self.min_duration = min_duration;
+        self
+    }
+
+    /// Sets the shortest duration that the transition animates for.
+    /// See [`Transition::with_min_duration`] for more details.
+    pub fn set_min_duration(&mut self, min_duration: Duration) {
+        self.min_duration = min_duration;
+    }
+
+    /// Offsets the duration of the transition by a deterministic amount derived from the `seed`
+    /// and returns the updated transition.
+    ///
+    /// The duration is scaled by a factor between `1.0 - amount` and `1.0 + amount`, so a group
+    /// of otherwise identical transitions with different seeds, e.g. their indices, don't move
+    /// in lockstep. The same seed always gives the same duration.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `amount` isn't in the range [0.0, 1.0).
+    pub fn with_jitter(mut self, seed: u64, amount: f32) -> Self {
+        assert!(
+            (0.0..1.0).contains(&amount),
+            "the jitter amount must be in the range [0.0, 1.0), found {amount}"
+        );
+        self.jitter = 1.0 + amount * (2.0 * unit_from_seed(seed) - 1.0);
+        self.refresh_duration();
+        self
     }
 
     /// Returns a reference to the current `value` of the transition.
@@ -70,14 +400,50 @@ where
         }
     }
 
+    /// Returns the instant at which the transition was last updated.
+    pub fn last_update(&self) -> Instant {
+        self.last_update
+    }
+
     /// Returns the transition's current [`Easing`] configuration.
     pub fn easing(&self) -> Easing {
         self.easing
     }
 
-    /// Returns the duration of the transition.
+    /// Returns the duration of the transition, including any [jitter](Transition::with_jitter).
     pub fn duration(&self) -> Duration {
-        self.easing.duration
+        self.easing.duration.mul_f64(f64::from(self.jitter))
+    }
+
+    /// Returns how far the transition is towards its current target, where `0.0` is the start
+    /// and `1.0` is the end. Transitions can be ordered by this value, e.g. to advance the
+    /// least progressed one first.
+    pub fn progress_value(&self) -> f32 {
+        self.progress.progress()
+    }
+
+    /// Returns the eased progress between the initial value and the target, i.e. the raw
+    /// progress passed through the curve. This is the position actually used to interpolate the
+    /// value, e.g. for a scrubber that shows where the value is rather than how much time passed.
+    pub fn eased_progress(&self) -> f32 {
+        self.eased(self.easing.curve, self.progress.value())
+    }
+
+    /// Returns the euclidean distance left between the current value and the target across all
+    /// components, e.g. to render at a lower quality while a lot is still moving.
+    pub fn total_remaining_distance(&self) -> f32 {
+        magnitude(&self.value.distance_to(self.target()))
+    }
+
+    /// Returns how much longer the transition will take to reach its current target.
+    ///
+    /// This accounts for the direction of the transition, so a transition that's reversing
+    /// only needs to cover the distance it has travelled from its initial value, as well as the
+    /// transition's speed multiplier.
+    pub fn remaining(&self) -> Duration {
+        let remaining = self.progress.remaining();
+        self.duration()
+            .mul_f32(remaining.max(0.0) / self.time_scale())
     }
 
     /// Reverses the transition, swapping the initial and target values
@@ -87,12 +453,18 @@ where
     /// and reset the progress to start from the beginning.
     pub fn reverse(&mut self) {
         if self.easing.reversible {
+            let position = self.eased(self.easing.curve, self.progress.value());
             self.progress.reverse();
+
+            if self.mirror_reverse {
+                self.seek_eased(position);
+            }
         } else {
             // If the transition isn't reversible, change the target value to the initial value
             // and reset the progress to start from the beginning.
             self.target = self.initial.clone();
             self.initial = self.value.clone();
+            self.distance = self.initial.distance_to(&self.target);
             self.progress = Progress::Forward(0.0);
         }
     }
@@ -111,6 +483,69 @@ where
         self.value = target.clone();
         self.target = target;
         self.progress = Progress::Forward(1.0);
+        self.distance = vec![0.0; T::components()];
+    }
+
+    /// Replays the transition from its initial value to its target, e.g. to run an attention
+    /// cue like a shake again after it has settled.
+    ///
+    /// Unlike [`Transition::set_target`], this doesn't change where the transition is heading;
+    /// it jumps back to the start of the original motion and animates it again from now.
+    pub fn restart(&mut self) {
+        self.progress = Progress::Forward(0.0);
+        self.value = self.initial.clone();
+        self.last_update = Instant::now();
+    }
+
+    /// Creates a [`Sequence`] that moves to the `target` with the given `easing` after this
+    /// transition ends. Chain more calls to [`Sequence::then`] to add more keyframes.
+    pub fn then(self, target: T, easing: Easing) -> Sequence<T> {
+        Sequence::new(self).then(target, easing)
+    }
+
+    /// Creates a new transition that is settled at the current value of this one, but keeps its
+    /// [`Easing`] and other configuration, e.g. to fork the state of an animation.
+    pub fn rebased(&self) -> Self {
+        let mut rebased = Self::new(self.value.clone())
+            .with_easing(self.easing)
+            .with_component_curves(self.component_curves.clone())
+            .with_normalized_speed(self.normalize_speed)
+            .with_frame_duration(self.frame_duration)
+            .with_speed(self.speed)
+            .with_reverse_speed(self.reverse_speed)
+            .with_mirrored_reverse(self.mirror_reverse);
+        rebased.duration_per_distance = self.duration_per_distance;
+        rebased.jitter = self.jitter;
+        rebased.min_duration = self.min_duration;
+        rebased.refresh_duration();
+        rebased
+    }
+
+    /// Blends the configuration and state of this transition with an `other` transition, where
+    /// `t` is how far to move from this transition towards the other, e.g. to morph between two
+    /// animation presets in an editor.
+    ///
+    /// The initial, current, and target values, the duration, and the progress are interpolated.
+    /// Curves can't be interpolated, so the curve and the rest of the configuration are taken
+    /// from this transition when `t` is less than `0.5` and from the `other` one otherwise.
+    /// The blended transition always moves forward towards its blended target.
+    pub fn blend_config(&self, other: &Transition<T>, t: f32) -> Transition<T> {
+        let mut blended = if t < 0.5 { self.clone() } else { other.clone() };
+        blended.initial.lerp(&self.initial, &other.initial, t);
+        blended.value.lerp(&self.value, &other.value, t);
+        blended.target.lerp(&self.target, &other.target, t);
+        blended.distance = blended.initial.distance_to(&blended.target);
+
+        // Durations can't be negative, so they're only blended within their own range.
+        let duration_t = f64::from(t.clamp(0.0, 1.0));
+        blended.easing.duration = self.easing.duration.mul_f64(1.0 - duration_t)
+            + other.easing.duration.mul_f64(duration_t);
+        blended.refresh_duration();
+
+        let mut progress = self.progress.value();
+        progress.lerp(&self.progress.value(), &other.progress.value(), t);
+        blended.progress = Progress::Forward(progress.clamp(0.0, 1.0));
+        blended
     }
 
     /// Updates the transition with details of the given `event`.
@@ -118,7 +553,9 @@ where
         match event {
             Event::Settle => self.settle(),
             Event::Tick(now) => self.tick(now),
-            Event::Target(target) => self.set_target(target),
+            Event::Target(target) => {
+                self.set_target(target);
+            }
             Event::SettleAt(target) => self.settle_at(target),
         }
     }
@@ -130,10 +567,13 @@ where
     }
 
     /// Interrupts the existing transition and starts a new one with the new `target`.
-    pub fn set_target(&mut self, target: T) {
-        // Don't do anything if the target hasn't changed.
-        if self.target() == &target {
-            return;
+    ///
+    /// Returns whether the target changed, e.g. to skip scheduling new frames when it didn't.
+    /// Targets with NaN or infinite components, e.g. from dividing by zero, are ignored.
+    pub fn set_target(&mut self, target: T) -> bool {
+        // Don't do anything if the target hasn't changed or can't be reached.
+        if self.target() == &target || !animate::is_finite(&target) {
+            return false;
         }
 
         // Reset the last update if the transition isn't moving.
@@ -157,9 +597,108 @@ where
             self.progress = Progress::Forward(0.0);
             self.initial = self.value.clone();
             self.target = target;
+            self.distance = self.initial.distance_to(&self.target);
+
+            if let Some(scaling) = self.duration_per_distance {
+                let duration = scaling
+                    .per_unit
+                    .mul_f64(f64::from(magnitude(&self.distance)));
+                self.set_duration(duration.clamp(scaling.min, scaling.max));
+            }
         }
 
         self.last_update = Instant::now();
+        true
+    }
+
+    /// Interrupts the existing transition and starts a new one with the new `target`, if the
+    /// target can be smoothly animated towards.
+    ///
+    /// This is a fallible version of [`Transition::set_target`] that reports targets that can't
+    /// be animated towards instead of ignoring them or jumping to parts of them, e.g. gradients
+    /// with different stops. The transition isn't changed when an error is returned.
+    pub fn try_set_target(&mut self, target: T) -> Result<bool, AnimateError> {
+        if !animate::is_finite(&target) {
+            return Err(AnimateError::NonFinite);
+        } else if !self.value.can_animate_to(&target) {
+            return Err(AnimateError::Incompatible);
+        }
+
+        Ok(self.set_target(target))
+    }
+
+    /// Interrupts the existing transition and starts a new one towards the `target` with the
+    /// given `easing` in a single step, e.g. to use a snappier easing for urgent changes.
+    ///
+    /// This is the same as calling [`Transition::set_easing`] followed by
+    /// [`Transition::set_target`], so the new target is handled the same way, e.g. going back to
+    /// the initial value reverses the transition with the new easing.
+    ///
+    /// Returns whether the target changed. Targets with NaN or infinite components are ignored.
+    pub fn set_target_with(&mut self, target: T, easing: Easing) -> bool {
+        self.set_easing(easing);
+        self.set_target(target)
+    }
+
+    /// Interrupts the existing transition with a new `target` while roughly preserving the
+    /// current speed of the value.
+    ///
+    /// Unlike [`Transition::set_target`], which restarts the curve from the beginning, this
+    /// picks a new duration so the value keeps moving at about the same speed across the change
+    /// in target. The duration of the transition's [`Easing`] is updated to the new duration.
+    /// The speed is matched most closely for curves with a constant rate like [`Curve::Linear`].
+    /// The new duration is kept between the [minimum duration](Transition::with_min_duration)
+    /// and four times the previous duration, e.g. when the value is barely moving.
+    ///
+    /// If the transition isn't animating, this behaves the same as [`Transition::set_target`].
+    pub fn retarget(&mut self, target: T) {
+        if !self.is_animating() || self.target() == &target || !animate::is_finite(&target) {
+            self.set_target(target);
+            return;
+        }
+
+        let speed = self.speed();
+        let distance = magnitude(&self.value.distance_to(&target));
+
+        self.progress = Progress::Forward(0.0);
+        self.initial = self.value.clone();
+        self.target = target;
+        self.distance = self.initial.distance_to(&self.target);
+
+        if speed > 0.0 && distance > 0.0 {
+            // The new transition moves forward, so its duration is scaled by the forward speed.
+            let max = self.easing.duration.mul_f64(MAX_RETARGET_SCALE);
+            let secs = distance * self.speed / speed / self.jitter;
+            let duration = Duration::from_secs_f32(secs.min(max.as_secs_f32()));
+            self.set_duration(duration.max(self.min_duration));
+        }
+    }
+
+    /// The multiplier applied to elapsed time in the current direction.
+    fn time_scale(&self) -> f32 {
+        match self.progress {
+            Progress::Forward(_) => self.speed,
+            Progress::Reverse(_) => self.speed * self.reverse_speed,
+        }
+    }
+
+    /// The approximate speed of the value at the current progress, in units per second of real
+    /// time, including the transition's speed multipliers.
+    fn speed(&self) -> f32 {
+        let duration = self.duration_secs;
+        if duration == 0.0 {
+            return 0.0;
+        }
+
+        // Estimate the slope of the curve around the current progress.
+        let progress = self.progress.value();
+        let start = (progress - SLOPE_STEP).max(0.0);
+        let end = (progress + SLOPE_STEP).min(1.0);
+        let curve = self.easing.curve;
+        let slope = (curve.value(end) - curve.value(start)) / (end - start);
+
+        magnitude(&self.target.distance_to(&self.initial)) * slope.abs() * self.time_scale()
+            / duration
     }
 
     /// Updates the transition's value based on the elapsed time since the last update.
@@ -171,28 +710,367 @@ where
         // Figure out how much time has passed since the last update
         let delta = now.duration_since(self.last_update);
         self.last_update = now;
+        self.advance(delta);
+    }
+
+    /// Resets the time of the last update to `now` without changing the progress.
+    ///
+    /// Cloning a transition copies the time it was last updated, so a clone that's ticked on a
+    /// different schedule would measure its first tick from the wrong point in time. This must
+    /// be called when moving a transition across clock contexts, e.g. right after cloning or
+    /// deserializing it.
+    ///
+    /// [`Transition::tick`] doesn't update settled transitions, so this can also be used to
+    /// record activity on a settled transition, e.g. for diagnostics.
+    pub fn rebase_clock(&mut self, now: Instant) {
+        self.last_update = now;
+    }
+
+    /// Moves the transition forward by the given `elapsed` time, without reading the clock.
+    ///
+    /// This is useful for fixed-timestep loops and for platforms where [`Instant::now`] is
+    /// unavailable or unreliable. The time of the last update isn't changed, so avoid mixing
+    /// this with [`Transition::tick`] on the same transition.
+    pub fn advance(&mut self, elapsed: Duration) {
+        if !self.is_animating() {
+            return;
+        }
+
+        if crate::is_reduced_motion() || self.duration() < self.min_duration {
+            self.settle();
+            return;
+        }
 
         self.progress
-            .update(delta.as_secs_f32() / self.easing.duration.as_secs_f32());
+            .update(elapsed.as_secs_f32() * self.time_scale() / self.duration_secs);
+        self.interpolate();
+    }
+
+    /// Predicts the value of the transition after the given `delta` of time without changing it,
+    /// e.g. to compute a layout ahead of time.
+    pub fn peek_at(&self, delta: Duration) -> T {
+        let mut transition = self.clone();
+        transition.advance(delta);
+        transition.value
+    }
+
+    /// Samples the value of the transition as if it started from its initial value at `start`,
+    /// and the time is now `now`, without changing it. This is useful for rendering frames at
+    /// exact timestamps, e.g. when exporting an animation.
+    ///
+    /// Times before `start` give the initial value.
+    pub fn value_at(&self, start: Instant, now: Instant) -> T {
+        self.value_after(now.saturating_duration_since(start))
+    }
+
+    /// Samples the value of the transition at `steps` evenly spaced times from its start to its
+    /// end without changing it, e.g. to measure the maximum speed or overshoot of an animation.
+    /// Each sample is paired with the time since the start of the transition, which accounts
+    /// for the transition's [speed](Transition::with_speed).
+    pub fn trajectory(&self, steps: usize) -> Vec<(Duration, T)> {
+        let step = if steps > 1 {
+            1.0 / (steps - 1) as f64
+        } else {
+            0.0
+        };
+
+        // Samples always move forward from the initial value, so they use the forward speed.
+        let span = self.duration().div_f64(f64::from(self.speed));
+
+        (0..steps)
+            .map(|i| {
+                let elapsed = span.mul_f64(i as f64 * step);
+                (elapsed, self.value_after(elapsed))
+            })
+            .collect()
+    }
+
+    /// The value of the transition after `elapsed` time from its initial value.
+    fn value_after(&self, elapsed: Duration) -> T {
+        let mut transition = self.clone();
+        transition.progress = Progress::Forward(0.0);
+        transition.value = transition.initial.clone();
+        transition.advance(elapsed);
+        transition.value
+    }
+
+    /// Moves the transition forward by the given number of `frames`, assuming each frame takes
+    /// the transition's frame duration. See [`Transition::with_frame_duration`].
+    pub fn tick_frames(&mut self, frames: u32) {
+        self.advance(self.frame_duration * frames);
+    }
+
+    /// Moves the transition to the given fraction `t` of the way from the initial value to the
+    /// target value, where `0.0` is the initial value and `1.0` is the target value.
+    ///
+    /// This is meant for driving a transition directly, e.g. from a drag gesture, and doesn't
+    /// change the time of the last update. Call [`Transition::release`] when the gesture ends to
+    /// resume animating over time.
+    pub fn set_progress_fraction(&mut self, t: f32) {
+        let t = t.clamp(0.0, 1.0);
+        self.progress = match self.progress {
+            Progress::Forward(_) => Progress::Forward(t),
+            Progress::Reverse(_) => Progress::Reverse(1.0 - t),
+        };
+        self.interpolate();
+    }
+
+    /// Resumes animating over time from the current progress, e.g. after a gesture
+    /// moved the transition with [`Transition::set_progress_fraction`].
+    ///
+    /// The transition continues towards whichever of the initial or target values is nearer.
+    pub fn release(&mut self) {
+        let t = self.progress.value();
+        self.progress = if t >= 0.5 {
+            Progress::Forward(t)
+        } else {
+            Progress::Reverse(1.0 - t)
+        };
+        self.last_update = Instant::now();
+        self.interpolate();
+    }
+
+    /// Updates the current value to match the current progress of the transition.
+    fn interpolate(&mut self) {
         if self.progress.is_complete() {
             // We're at the target - assign the current value to the target value.
             // This ensures that the value is exactly the target value, even if the
             // curve doesn't reach it or the animation implementation isn't correct.
             self.value = self.target().clone();
-        } else {
+        } else if self.component_curves.is_empty() {
             // Continue to lerp the value towards the target
             self.value.lerp(
                 &self.initial,
                 &self.target,
-                self.easing.curve.value(self.progress.value()),
+                self.eased(self.easing.curve, self.progress.value()),
             );
+        } else {
+            // Ease each component along its own curve, starting from the initial value.
+            let distance = self.target.distance_to(&self.initial);
+            // With normalized speed, the easing's curve sets the progress along the path
+            // traced by the component curves, which is already mirrored when reversing.
+            let (progress, is_path) = if self.normalize_speed {
+                let fraction = self.eased(self.easing.curve, self.progress.value());
+                (self.path_progress(&distance, fraction), true)
+            } else {
+                (self.progress.value(), false)
+            };
+            let mut components = distance.into_iter().enumerate().map(|(index, distance)| {
+                let curve = self.curve_for(index);
+                if is_path {
+                    distance * curve.value(progress)
+                } else {
+                    distance * self.eased(curve, progress)
+                }
+            });
+            let mut value = self.initial.clone();
+            value.update(&mut components);
+            self.value = value;
+        }
+    }
+
+    /// Caches the duration in seconds after the easing's duration or the jitter changes.
+    fn refresh_duration(&mut self) {
+        self.duration_secs = self.duration().as_secs_f32();
+    }
+
+    /// The value of the `curve` at the given `progress`, mirrored while reversing if the
+    /// transition uses a mirrored reverse.
+    fn eased(&self, curve: Curve, progress: f32) -> f32 {
+        match self.progress {
+            Progress::Reverse(_) if self.mirror_reverse => 1.0 - curve.value(1.0 - progress),
+            _ => curve.value(progress),
+        }
+    }
+
+    /// Moves the progress to the point on the current curve that matches the given `position`.
+    fn seek_eased(&mut self, position: f32) {
+        let t = self.solve_eased(self.easing.curve, position);
+        self.progress = match self.progress {
+            Progress::Forward(_) => Progress::Forward(t),
+            Progress::Reverse(_) => Progress::Reverse(1.0 - t),
+        };
+    }
+
+    /// The progress at which [`Transition::eased`] reaches the given `position`, assuming the
+    /// curve doesn't move backwards.
+    fn solve_eased(&self, curve: Curve, position: f32) -> f32 {
+        let (mut low, mut high) = (0.0, 1.0);
+        for _ in 0..32 {
+            let mid = (low + high) / 2.0;
+            if self.eased(curve, mid) < position {
+                low = mid;
+            } else {
+                high = mid;
+            }
         }
+
+        (low + high) / 2.0
+    }
+
+    /// The progress at which the value has covered the given `fraction` of the length of the
+    /// path traced by the component curves over the given component `distance`.
+    fn path_progress(&self, distance: &[f32], fraction: f32) -> f32 {
+        let point = |progress: f32| -> Vec<f32> {
+            distance
+                .iter()
+                .enumerate()
+                .map(|(index, distance)| distance * self.curve_for(index).value(progress))
+                .collect()
+        };
+
+        // Approximate the path with line segments, keeping the length up to each sample.
+        let step = 1.0 / PATH_SAMPLES as f32;
+        let mut previous = point(0.0);
+        let mut lengths = Vec::with_capacity(PATH_SAMPLES);
+        let mut total = 0.0;
+        for i in 1..=PATH_SAMPLES {
+            let next = point(i as f32 * step);
+            let segment: Vec<f32> = next.iter().zip(&previous).map(|(a, b)| a - b).collect();
+            total += magnitude(&segment);
+            lengths.push(total);
+            previous = next;
+        }
+
+        if total == 0.0 {
+            return fraction;
+        }
+
+        // Find the segment containing the target length and interpolate within it.
+        let length = fraction * total;
+        let index = lengths
+            .iter()
+            .position(|l| *l >= length)
+            .unwrap_or(PATH_SAMPLES - 1);
+        let start = if index == 0 { 0.0 } else { lengths[index - 1] };
+        let segment = lengths[index] - start;
+        let within = if segment > 0.0 {
+            (length - start) / segment
+        } else {
+            0.0
+        };
+
+        (index as f32 + within) * step
+    }
+
+    /// The curve used to ease the component at the given `index`.
+    fn curve_for(&self, index: usize) -> Curve {
+        self.component_curves
+            .iter()
+            .find(|(range, _)| range.contains(&index))
+            .map_or(self.easing.curve, |(_, curve)| *curve)
     }
 
     /// Whether this transition is currently animating towards its target.
     pub fn is_animating(&self) -> bool {
         !self.progress.is_complete()
     }
+
+    /// Whether the transition has finished and is resting at its target value, e.g. a toggle
+    /// that has finished turning on.
+    pub fn is_at_target(&self) -> bool {
+        matches!(self.progress, Progress::Forward(_)) && self.progress.is_complete()
+    }
+
+    /// Whether the transition has finished and is resting at its initial value after being
+    /// reversed, e.g. a toggle that has finished turning back off.
+    pub fn is_at_initial(&self) -> bool {
+        matches!(self.progress, Progress::Reverse(_)) && self.progress.is_complete()
+    }
+
+    /// Whether the transition is within `epsilon` of finishing, measured in remaining progress,
+    /// e.g. to enable interaction once a panel is 95% open with an `epsilon` of `0.05`.
+    ///
+    /// Unlike [`Transition::is_animating`], this is true slightly before the transition
+    /// finishes. Transitions that have finished are always near.
+    pub fn is_near(&self, epsilon: f32) -> bool {
+        !self.is_animating() || self.progress.remaining() <= epsilon
+    }
+
+    /// Whether this transition is approximately equal to an `other` one, ignoring differences
+    /// smaller than `epsilon` caused by floating-point noise.
+    ///
+    /// The initial, current, and target values are compared by the magnitude of the distance
+    /// between them, and the progress must be in the same direction and within `epsilon`. The
+    /// configuration must match exactly, while the time of the last update is ignored.
+    pub fn approx_eq(&self, other: &Self, epsilon: f32) -> bool {
+        let is_near = |a: &T, b: &T| magnitude(&a.distance_to(b)) <= epsilon;
+        let is_same_direction = matches!(
+            (self.progress, other.progress),
+            (Progress::Forward(_), Progress::Forward(_))
+                | (Progress::Reverse(_), Progress::Reverse(_))
+        );
+
+        is_same_direction
+            && (self.progress.progress() - other.progress.progress()).abs() <= epsilon
+            && is_near(&self.initial, &other.initial)
+            && is_near(&self.value, &other.value)
+            && is_near(&self.target, &other.target)
+            && self.easing == other.easing
+            && self.component_curves == other.component_curves
+            && self.normalize_speed == other.normalize_speed
+            && self.frame_duration == other.frame_duration
+            && self.speed == other.speed
+            && self.reverse_speed == other.reverse_speed
+            && self.mirror_reverse == other.mirror_reverse
+            && self.duration_per_distance == other.duration_per_distance
+            && self.jitter == other.jitter
+            && self.min_duration == other.min_duration
+    }
+
+    /// Which components are changing between the initial and target values of this transition,
+    /// in the same order as [`Animate::distance_to`].
+    ///
+    /// This can be used to only invalidate the parts of a value that are actually moving,
+    /// e.g. only the `x` of a `Point` when its `y` is unchanged.
+    pub fn changing_components(&self) -> Vec<bool> {
+        self.distance.iter().map(|d| *d != 0.0).collect()
+    }
+}
+
+#[cfg(feature = "subscription")]
+impl<T> Transition<T>
+where
+    T: Animate + 'static,
+{
+    /// Returns a [`Subscription`](iced_runtime::futures::Subscription) that produces an
+    /// [`Event::Tick`] on every frame while the transition is animating, and nothing otherwise.
+    ///
+    /// This requires the `subscription` feature. Map the events to one of your messages and
+    /// pass them back to [`Transition::update`], e.g.
+    /// `transition.subscription().map(Message::Animate)`.
+    pub fn subscription(&self) -> iced_runtime::futures::Subscription<Event<T>> {
+        if self.is_animating() {
+            iced_runtime::window::frames().map(Event::Tick)
+        } else {
+            iced_runtime::futures::Subscription::none()
+        }
+    }
+}
+
+impl<T> Default for Transition<T>
+where
+    T: Animate + Default,
+{
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+/// A deterministic number in the range [0.0, 1.0) derived from the `seed` using SplitMix64.
+fn unit_from_seed(seed: u64) -> f32 {
+    let mut z = seed.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^= z >> 31;
+
+    // Use the top 24 bits, which an `f32` can represent exactly.
+    (z >> 40) as f32 / (1u64 << 24) as f32
+}
+
+/// The euclidean length of the given component `distances`.
+fn magnitude(distances: &[f32]) -> f32 {
+    distances.iter().map(|d| d * d).sum::<f32>().sqrt()
 }
 
 #[cfg(test)]
@@ -237,7 +1115,27 @@ mod tests {
         assert_eq!(*transition.value(), new_target);
     }
 
-    /// Transitions should be reversible such that changing targets in the middle of an
+    /// The reversible flag should control whether targeting the initial value reverses or restarts.
+    #[test]
+    fn with_reversible() {
+        let mut transition = Transition::new(0.0)
+            .to(1.0)
+            .with_easing(Easing::LINEAR.reversible(true))
+            .with_reversible(false);
+        assert!(!transition.easing().reversible);
+        transition.advance(DEFAULT_DURATION / 2);
+
+        transition.set_target(0.0);
+        assert_eq!(transition.progress, Progress::Forward(0.0));
+        assert_eq!(*transition.target(), 0.0);
+
+        transition.set_reversible(true);
+        transition.advance(DEFAULT_DURATION / 2);
+        transition.set_target(0.5);
+        assert!(matches!(transition.progress, Progress::Reverse(_)));
+    }
+
+    /// Transitions should be reversible such that changing targets in the middle of an
     /// animation will reverse the animation if the target is the initial value.
     #[test]
     fn reversible_transitions() {
@@ -289,4 +1187,776 @@ mod tests {
         assert!(!transition.is_animating());
         assert_eq!(transition.progress, Progress::Forward(1.0));
     }
+
+    /// Restarting a settled transition should replay it from the initial value.
+    #[test]
+    fn restart() {
+        let mut transition = Transition::new(0.0).to(1.0);
+        transition.settle();
+        assert_eq!(*transition.value(), 1.0);
+
+        transition.restart();
+        assert!(transition.is_animating());
+        assert_eq!(*transition.value(), 0.0);
+        assert_eq!(*transition.target(), 1.0);
+
+        let halfway = transition.last_update + DEFAULT_DURATION / 2;
+        transition.tick(halfway);
+        assert!(*transition.value() > 0.0 && *transition.value() < 1.0);
+
+        transition.tick(halfway + DEFAULT_DURATION);
+        assert_eq!(*transition.value(), 1.0);
+    }
+
+    /// Blending two transitions should interpolate their values, durations, and progress.
+    #[test]
+    fn blend_config() {
+        let mut first = Transition::new(0.0)
+            .to(1.0)
+            .with_easing(Easing::LINEAR.with_duration(Duration::from_millis(200)));
+        first.set_progress_fraction(0.5);
+        let second = Transition::new(10.0)
+            .to(20.0)
+            .with_easing(Easing::EASE.with_duration(Duration::from_millis(600)));
+
+        let blended = first.blend_config(&second, 0.5);
+        assert_eq!(blended.initial, 5.0);
+        assert_eq!(*blended.value(), 5.25);
+        assert_eq!(*blended.target(), 10.5);
+        assert_eq!(blended.progress, Progress::Forward(0.25));
+        let duration = blended.duration().as_secs_f32();
+        assert!((duration - 0.4).abs() < 1e-6);
+        assert_eq!(blended.easing().curve, Curve::Ease);
+
+        assert_eq!(
+            first.blend_config(&second, 0.25).easing().curve,
+            Curve::Linear
+        );
+    }
+
+    /// The remaining duration should depend on the direction of the transition.
+    #[test]
+    fn remaining() {
+        let mut forward = Transition::new(0.0)
+            .to(1.0)
+            .with_easing(Easing::LINEAR.reversible(true));
+        forward.set_progress_fraction(0.3);
+        let mut reversing = forward.clone();
+        reversing.reverse();
+
+        // Both are 30% of the way from the initial value, but the reversing transition only
+        // needs to travel back that 30% to reach its target.
+        assert_eq!(forward.value(), reversing.value());
+        let duration = DEFAULT_DURATION.as_secs_f32();
+        assert!((forward.remaining().as_secs_f32() - duration * 0.7).abs() < 1e-6);
+        assert!((reversing.remaining().as_secs_f32() - duration * 0.3).abs() < 1e-6);
+
+        let slow = forward.clone().with_speed(0.5);
+        assert!((slow.remaining().as_secs_f32() - duration * 1.4).abs() < 1e-6);
+
+        forward.settle();
+        assert_eq!(forward.remaining(), Duration::ZERO);
+    }
+
+    /// Transitions with a different initial value should animate towards the original value.
+    #[test]
+    fn with_initial() {
+        let mut transition = Transition::new(1.0).with_initial(0.0);
+        assert!(transition.is_animating());
+        assert_eq!(*transition.value(), 0.0);
+        assert_eq!(*transition.target(), 1.0);
+
+        transition.tick(transition.last_update + DEFAULT_DURATION / 2);
+        assert!(*transition.value() > 0.0 && *transition.value() < 1.0);
+
+        transition.tick(transition.last_update + DEFAULT_DURATION);
+        assert_eq!(*transition.value(), 1.0);
+        assert!(!Transition::new(1.0).with_initial(1.0).is_animating());
+    }
+
+    /// Transitions created partway through should start at the eased value for that progress.
+    #[test]
+    fn with_progress() {
+        let transition = Transition::new(0.0)
+            .to(10.0)
+            .with_easing(Easing::EASE_IN)
+            .with_progress(Progress::Forward(0.5));
+        assert!(transition.is_animating());
+        assert!((transition.value() - 10.0 * Curve::EaseIn.value(0.5)).abs() < 1e-5);
+    }
+
+    /// Progress outside of the range [0.0, 1.0] should be rejected.
+    #[test]
+    #[should_panic]
+    fn with_invalid_progress() {
+        let _ = Transition::new(0.0)
+            .to(1.0)
+            .with_progress(Progress::Forward(1.5));
+    }
+
+    /// Larger changes should take proportionally longer when scaling the duration by distance.
+    #[test]
+    fn duration_per_distance() {
+        let transition = Transition::new(0.0).with_duration_per_distance(
+            Duration::from_millis(20),
+            Duration::from_millis(100),
+            Duration::from_millis(1000),
+        );
+
+        let small = transition.clone().to(1.0);
+        let medium = transition.clone().to(10.0);
+        let large = transition.clone().to(20.0);
+        let huge = transition.to(100.0);
+
+        // Durations are clamped to the minimum and maximum.
+        assert_eq!(small.duration(), Duration::from_millis(100));
+        assert_eq!(medium.duration(), Duration::from_millis(200));
+        assert_eq!(large.duration(), Duration::from_millis(400));
+        assert_eq!(huge.duration(), Duration::from_millis(1000));
+    }
+
+    /// Reversing with a mirrored curve should keep the value in place, then ease back along
+    /// the mirrored curve.
+    #[test]
+    fn mirrored_reverse() {
+        let mut transition = Transition::new(0.0)
+            .to(1.0)
+            .with_easing(Easing::EASE_IN.reversible(true))
+            .with_mirrored_reverse(true);
+        let start = transition.last_update;
+
+        transition.tick(start + DEFAULT_DURATION / 2);
+        let before = *transition.value();
+        transition.reverse();
+        transition.tick(start + DEFAULT_DURATION / 2);
+        assert!((transition.value() - before).abs() < 1e-4);
+
+        // The return trip follows an ease out curve instead of retracing the ease in curve.
+        transition.tick(start + DEFAULT_DURATION * 3 / 4);
+        let progress = transition.progress.value();
+        assert!((transition.value() - Curve::EaseOut.value(progress)).abs() < 1e-4);
+
+        transition.tick(start + DEFAULT_DURATION * 2);
+        assert_eq!(*transition.value(), 0.0);
+    }
+
+    /// A reverse speed of 2.0 should make the reverse leg take half as long as the forward one.
+    #[test]
+    fn reverse_speed() {
+        let mut transition = Transition::new(0.0)
+            .to(1.0)
+            .with_easing(Easing::LINEAR.reversible(true))
+            .with_reverse_speed(2.0);
+
+        transition.advance(DEFAULT_DURATION / 2);
+        assert_eq!(*transition.value(), 0.5);
+        transition.advance(DEFAULT_DURATION / 2);
+        assert_eq!(*transition.value(), 1.0);
+
+        transition.reverse();
+        assert_eq!(transition.remaining(), DEFAULT_DURATION / 2);
+        transition.advance(DEFAULT_DURATION / 4);
+        assert_eq!(*transition.value(), 0.5);
+        transition.advance(DEFAULT_DURATION / 4);
+        assert_eq!(*transition.value(), 0.0);
+        assert!(!transition.is_animating());
+    }
+
+    /// The cached duration should stay in sync with the easing and advance the same way.
+    #[test]
+    fn cached_duration() {
+        let mut transition = Transition::new(0.0).to(1.0).with_easing(Easing::LINEAR);
+        assert_eq!(transition.duration_secs, DEFAULT_DURATION.as_secs_f32());
+
+        transition.set_duration(Duration::from_secs(2));
+        assert_eq!(transition.duration_secs, 2.0);
+        transition.advance(Duration::from_millis(500));
+        assert_eq!(*transition.value(), 0.25);
+
+        let mut jittered = Transition::new(0.0)
+            .to(1.0)
+            .with_easing(Easing::LINEAR)
+            .with_jitter(7, 0.5);
+        assert_eq!(jittered.duration_secs, jittered.duration().as_secs_f32());
+        jittered.set_easing(Easing::LINEAR.with_duration(Duration::from_secs(1)));
+        assert_eq!(jittered.duration_secs, jittered.duration().as_secs_f32());
+        assert_eq!(jittered.rebased().duration_secs, jittered.duration_secs);
+    }
+
+    /// Default transitions should be settled at the default value with the default easing.
+    #[test]
+    fn default() {
+        let transition = Transition::<f32>::default();
+        assert_eq!(*transition.value(), 0.0);
+        assert!(!transition.is_animating());
+        assert_eq!(transition.duration(), DEFAULT_DURATION);
+        assert_eq!(transition.easing(), Easing::default());
+    }
+
+    /// Transitions should be near their target once the remaining progress is within epsilon.
+    #[test]
+    fn is_near() {
+        let mut transition = Transition::new(0.0).to(1.0);
+        assert!(!transition.is_near(0.05));
+
+        transition.set_progress_fraction(0.96);
+        assert!(transition.is_animating());
+        assert!(transition.is_near(0.05));
+        assert!(!transition.is_near(0.01));
+
+        transition.settle();
+        assert!(transition.is_near(0.0));
+    }
+
+    /// Transitions shorter than the minimum duration should settle on the next update.
+    #[test]
+    fn min_duration() {
+        for duration in [
+            Duration::ZERO,
+            Duration::from_nanos(1),
+            Duration::from_micros(500),
+        ] {
+            let mut transition = Transition::new(0.0)
+                .to(1.0)
+                .with_easing(Easing::LINEAR.with_duration(duration));
+            transition.advance(Duration::ZERO);
+            assert_eq!(*transition.value(), 1.0);
+            assert_eq!(transition.progress_value(), 1.0);
+            assert!(!transition.is_animating());
+        }
+
+        // A lower minimum duration lets shorter transitions animate.
+        let mut transition = Transition::new(0.0)
+            .to(1.0)
+            .with_easing(Easing::LINEAR.with_duration(Duration::from_micros(500)))
+            .with_min_duration(Duration::ZERO);
+        transition.advance(Duration::from_micros(250));
+        assert_eq!(*transition.value(), 0.5);
+    }
+
+    /// Targets that can't be animated towards should be reported without changing anything.
+    #[test]
+    fn try_set_target() {
+        use iced_core::{gradient::Linear, Color, Gradient, Radians};
+
+        let two_stops = Gradient::Linear(
+            Linear::new(Radians(0.0))
+                .add_stop(0.0, Color::BLACK)
+                .add_stop(1.0, Color::WHITE),
+        );
+        let three_stops = Gradient::Linear(
+            Linear::new(Radians(0.0))
+                .add_stop(0.0, Color::WHITE)
+                .add_stop(0.5, Color::BLACK)
+                .add_stop(1.0, Color::WHITE),
+        );
+        let mut transition = Transition::new(two_stops);
+        let before = transition.clone();
+
+        assert_eq!(
+            transition.try_set_target(three_stops),
+            Err(AnimateError::Incompatible)
+        );
+        assert_eq!(transition, before);
+
+        let reversed = Gradient::Linear(
+            Linear::new(Radians(0.0))
+                .add_stop(0.0, Color::WHITE)
+                .add_stop(1.0, Color::BLACK),
+        );
+        assert_eq!(transition.try_set_target(reversed), Ok(true));
+        assert_eq!(transition.target(), &reversed);
+
+        let mut transition = Transition::new(0.0);
+        assert_eq!(
+            transition.try_set_target(f32::NAN),
+            Err(AnimateError::NonFinite)
+        );
+    }
+
+    /// Non-finite targets should be ignored so the value stays finite and settles.
+    #[test]
+    fn non_finite_target() {
+        let mut transition = Transition::new(0.0).to(1.0).with_easing(Easing::LINEAR);
+        assert!(!transition.set_target(f32::NAN));
+        assert!(!transition.set_target(f32::INFINITY));
+        transition.retarget(f32::NEG_INFINITY);
+        assert_eq!(transition.target(), &1.0);
+
+        transition.advance(DEFAULT_DURATION);
+        assert_eq!(*transition.value(), 1.0);
+        assert!(!transition.is_animating());
+    }
+
+    /// Rebasing the clock of a settled transition should only change the last update.
+    #[test]
+    fn rebase_clock_when_settled() {
+        let mut transition = Transition::new(1.0);
+        let now = transition.last_update() + Duration::from_secs(1);
+
+        transition.tick(now);
+        assert!(transition.last_update() < now);
+
+        transition.rebase_clock(now);
+        assert_eq!(transition.last_update(), now);
+        assert_eq!(*transition.value(), 1.0);
+        assert!(!transition.is_animating());
+    }
+
+    /// Transitions and curves should be able to cross thread boundaries, which rules out custom
+    /// curves that capture state that isn't thread-safe.
+    #[test]
+    fn send_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<Curve>();
+        assert_send_sync::<Easing>();
+        assert_send_sync::<Transition<f32>>();
+    }
+
+    /// The remaining distance should shrink as the transition progresses.
+    #[test]
+    fn total_remaining_distance() {
+        let mut transition = Transition::new((0.0, 0.0))
+            .to((3.0, 4.0))
+            .with_easing(Easing::LINEAR);
+        let mut previous = transition.total_remaining_distance();
+        assert!((previous - 5.0).abs() < 1e-6);
+
+        while transition.is_animating() {
+            transition.advance(Duration::from_millis(50));
+            let remaining = transition.total_remaining_distance();
+            assert!(remaining < previous);
+            previous = remaining;
+        }
+        assert!(previous < 1e-6);
+    }
+
+    /// Setting the target and easing together should start a new transition with both.
+    #[test]
+    fn set_target_with() {
+        let mut transition = Transition::new(0.0).to(1.0).with_easing(Easing::EASE_IN);
+        transition.advance(DEFAULT_DURATION / 2);
+        let before = *transition.value();
+
+        let easing = Easing::LINEAR.with_duration(Duration::from_millis(100));
+        assert!(transition.set_target_with(2.0, easing));
+        assert_eq!(transition.target(), &2.0);
+        assert_eq!(transition.easing(), easing);
+        assert_eq!(*transition.value(), before);
+
+        transition.advance(Duration::from_millis(50));
+        assert!((transition.value() - (before + 2.0) / 2.0).abs() < 1e-4);
+
+        // An unchanged target only updates the easing.
+        assert!(!transition.set_target_with(2.0, Easing::EASE));
+        assert_eq!(transition.easing(), Easing::EASE);
+
+        // Going back to the initial value reverses like `set_target`.
+        let mut transition = Transition::new(0.0).to(1.0);
+        transition.advance(DEFAULT_DURATION / 2);
+        let easing = Easing::LINEAR.reversible(true);
+        assert!(transition.set_target_with(0.0, easing));
+        assert!(matches!(transition.progress, Progress::Reverse(_)));
+        assert_eq!(transition.easing(), easing);
+    }
+
+    /// Setting the target and easing together should still scale the duration by distance.
+    #[test]
+    fn set_target_with_duration_per_distance() {
+        let per_unit = Duration::from_millis(200);
+        let mut transition = Transition::new(0.0).with_duration_per_distance(
+            per_unit,
+            Duration::ZERO,
+            Duration::from_secs(10),
+        );
+        assert!(transition.set_target_with(3.0, Easing::LINEAR));
+        assert_eq!(transition.easing().duration, per_unit * 3);
+        assert_eq!(transition.easing().curve, Curve::Linear);
+    }
+
+    /// Changing the curve mid-flight should continue from the current value.
+    #[test]
+    fn set_curve() {
+        let mut transition = Transition::new(0.0).to(1.0).with_easing(Easing::EASE_IN);
+        let start = transition.last_update;
+
+        transition.tick(start + DEFAULT_DURATION / 2);
+        let before = *transition.value();
+        transition.set_curve(Curve::Linear);
+        assert_eq!(transition.easing().curve, Curve::Linear);
+
+        // Progress moves to the matching point on the new curve.
+        transition.tick(start + DEFAULT_DURATION / 2);
+        assert!((transition.value() - before).abs() < 1e-4);
+
+        transition.tick(start + DEFAULT_DURATION * 2);
+        assert_eq!(*transition.value(), 1.0);
+    }
+
+    /// Jitter should depend only on the seed and stay within the given amount.
+    #[test]
+    fn jitter() {
+        let duration = |seed| Transition::new(0.0).with_jitter(seed, 0.2).duration();
+        assert_eq!(duration(1), duration(1));
+        assert_ne!(duration(1), duration(2));
+
+        for seed in 0..100 {
+            let duration = duration(seed);
+            assert!(duration >= DEFAULT_DURATION.mul_f32(0.8));
+            assert!(duration <= DEFAULT_DURATION.mul_f32(1.2));
+        }
+
+        // The jittered duration is used when advancing.
+        let mut transition = Transition::new(0.0).to(1.0).with_jitter(3, 0.2);
+        transition.advance(transition.duration() / 2);
+        assert!((transition.progress_value() - 0.5).abs() < 1e-4);
+    }
+
+    /// Rebased transitions should be settled at the current value with the same easing.
+    #[test]
+    fn rebased() {
+        let easing = Easing::EASE_IN.with_duration(Duration::from_millis(200));
+        let mut transition = Transition::new(0.0).to(1.0).with_easing(easing);
+        transition.tick(transition.last_update + Duration::from_millis(100));
+
+        let rebased = transition.rebased();
+        assert!(!rebased.is_animating());
+        assert_eq!(rebased.value(), transition.value());
+        assert_eq!(rebased.target(), transition.value());
+        assert_eq!(rebased.easing().curve, Curve::EaseIn);
+        assert_eq!(rebased.duration(), Duration::from_millis(200));
+    }
+
+    /// Ticks after rebasing the clock should be measured from the rebase point.
+    #[test]
+    fn rebase_clock() {
+        let mut transition = Transition::new(0.0).to(1.0).with_easing(Easing::LINEAR);
+        let rebase = transition.last_update + DEFAULT_DURATION;
+
+        transition.rebase_clock(rebase);
+        assert_eq!(*transition.value(), 0.0);
+        assert!(transition.is_animating());
+
+        transition.tick(rebase + DEFAULT_DURATION / 4);
+        assert!((transition.value() - 0.25).abs() < 1e-6);
+    }
+
+    /// Doubling the speed should make progress twice as fast over the same time.
+    #[test]
+    fn speed_multiplier() {
+        let mut normal = Transition::new(0.0).to(1.0);
+        let mut fast = normal.clone().with_speed(2.0);
+        let elapsed = DEFAULT_DURATION / 4;
+
+        normal.advance(elapsed);
+        fast.advance(elapsed);
+        assert!((normal.progress.value() - 0.25).abs() < 1e-6);
+        assert!((fast.progress.value() - 0.5).abs() < 1e-6);
+    }
+
+    /// Speed multipliers that aren't positive should be rejected.
+    #[test]
+    #[should_panic]
+    fn zero_speed_multiplier() {
+        Transition::new(0.0).set_speed(0.0);
+    }
+
+    /// Peeking should predict future values without changing the transition.
+    #[test]
+    fn peek_at() {
+        let transition = Transition::new(0.0).to(1.0).with_easing(Easing::EASE_IN);
+        let before = transition.clone();
+
+        assert_eq!(transition.peek_at(DEFAULT_DURATION), 1.0);
+        let halfway = transition.peek_at(DEFAULT_DURATION / 2);
+        assert!((halfway - Curve::EaseIn.value(0.5)).abs() < 1e-6);
+        assert_eq!(transition, before);
+    }
+
+    /// Eased progress should follow the curve while the raw progress follows time.
+    #[test]
+    fn eased_progress() {
+        let mut transition = Transition::new(0.0).to(1.0).with_easing(Easing::EASE_IN);
+        transition.advance(DEFAULT_DURATION / 2);
+        assert!((transition.progress_value() - 0.5).abs() < 1e-6);
+        assert!((transition.eased_progress() - Curve::EaseIn.value(0.5)).abs() < 1e-6);
+        assert!(transition.eased_progress() < transition.progress_value());
+        assert_eq!(transition.eased_progress(), *transition.value());
+    }
+
+    /// Sampling at absolute times should follow the curve from the start of the transition.
+    #[test]
+    fn value_at() {
+        let mut transition = Transition::new(0.0).to(1.0).with_easing(Easing::EASE_IN);
+        transition.advance(DEFAULT_DURATION / 4);
+        let before = transition.clone();
+
+        let start = Instant::now();
+        let halfway = transition.value_at(start, start + DEFAULT_DURATION / 2);
+        assert!((halfway - Curve::EaseIn.value(0.5)).abs() < 1e-6);
+        assert_eq!(transition.value_at(start, start), 0.0);
+        assert_eq!(
+            transition.value_at(start, start + DEFAULT_DURATION * 2),
+            1.0
+        );
+        assert_eq!(transition, before);
+    }
+
+    /// Trajectories should run from the initial value to the target over the duration.
+    #[test]
+    fn trajectory() {
+        let transition = Transition::new(0.0)
+            .to(1.0)
+            .with_easing(Easing::EASE_IN_OUT);
+        let trajectory = transition.trajectory(11);
+
+        assert_eq!(trajectory.len(), 11);
+        assert_eq!(trajectory.first(), Some(&(Duration::ZERO, 0.0)));
+        assert_eq!(trajectory.last(), Some(&(DEFAULT_DURATION, 1.0)));
+        assert!(trajectory.windows(2).all(|pair| pair[0].1 <= pair[1].1));
+        assert!(transition.trajectory(0).is_empty());
+    }
+
+    /// Trajectories should still end at the target when the transition runs at another speed.
+    #[test]
+    fn trajectory_with_speed() {
+        for speed in [0.5, 2.0] {
+            let transition = Transition::new(0.0)
+                .to(1.0)
+                .with_easing(Easing::LINEAR)
+                .with_speed(speed);
+            let trajectory = transition.trajectory(5);
+
+            let (elapsed, value) = trajectory.last().copied().unwrap();
+            assert_eq!(elapsed, DEFAULT_DURATION.div_f32(speed));
+            assert_eq!(value, 1.0);
+            assert!((trajectory[2].1 - 0.5).abs() < 1e-6);
+        }
+    }
+
+    /// Ticking by frames should match advancing by the same amount of time.
+    #[test]
+    fn tick_frames() {
+        let easing = Easing::EASE.with_duration(Duration::from_secs(1));
+        let mut frames = Transition::new(0.0).to(1.0).with_easing(easing);
+        let mut advanced = frames.clone();
+
+        // 30 frames at 60fps should be the same as 500ms.
+        frames.tick_frames(30);
+        advanced.advance(Duration::from_millis(500));
+
+        assert!((frames.value() - advanced.value()).abs() < 1e-4);
+        assert!(frames.is_animating());
+
+        // Frame durations are rounded down, so tick one extra frame to pass the end.
+        frames.tick_frames(31);
+        assert_eq!(*frames.value(), 1.0);
+        assert!(!frames.is_animating());
+    }
+
+    /// Components with their own curve should follow that curve instead of the easing's curve.
+    #[test]
+    fn component_curves() {
+        let mut transition = Transition::new((0.0, 0.0))
+            .to((1.0, 1.0))
+            .with_easing(Easing::LINEAR)
+            .with_component_curves(vec![(1..2, Curve::EaseIn)]);
+        let halfway = Instant::now() + DEFAULT_DURATION / 2;
+
+        transition.tick(halfway);
+        let progress = transition.progress.value();
+        let (x, y) = *transition.value();
+        assert!((x - progress).abs() < 1e-6);
+        assert!((y - Curve::EaseIn.value(progress)).abs() < 1e-6);
+        assert!(y < x);
+
+        let done = Instant::now() + DEFAULT_DURATION + Duration::from_millis(1);
+        transition.tick(done);
+        assert_eq!(*transition.value(), (1.0, 1.0));
+    }
+
+    /// Releasing a transition past its halfway point should continue towards the target.
+    #[test]
+    fn release_after_scrubbing() {
+        let mut transition = Transition::new(0.0).to(1.0);
+        transition.set_progress_fraction(0.7);
+        assert!((transition.value() - 0.7).abs() < 1e-6);
+        assert!(transition.is_animating());
+
+        transition.release();
+        assert_eq!(*transition.target(), 1.0);
+
+        let done = Instant::now() + DEFAULT_DURATION;
+        transition.tick(done);
+        assert_eq!(*transition.value(), 1.0);
+        assert!(!transition.is_animating());
+    }
+
+    /// Releasing a transition before its halfway point should return to the initial value.
+    #[test]
+    fn release_returns_to_initial() {
+        let mut transition = Transition::new(0.0).to(1.0);
+        transition.set_progress_fraction(0.2);
+        transition.release();
+        assert_eq!(*transition.target(), 0.0);
+
+        let done = Instant::now() + DEFAULT_DURATION;
+        transition.tick(done);
+        assert_eq!(*transition.value(), 0.0);
+    }
+
+    /// Retargeting mid-flight should keep the value moving at about the same speed.
+    #[test]
+    fn retarget_preserves_speed() {
+        let mut transition = Transition::new(0.0).to(1.0).with_easing(Easing::LINEAR);
+        let start = transition.last_update;
+        let frame = Duration::from_millis(16);
+
+        transition.tick(start + DEFAULT_DURATION / 2);
+        let before = *transition.value();
+        transition.tick(start + DEFAULT_DURATION / 2 + frame);
+        let speed_before = (transition.value() - before) / frame.as_secs_f32();
+
+        transition.retarget(2.0);
+        let seam = *transition.value();
+        transition.tick(start + DEFAULT_DURATION / 2 + frame * 2);
+        let speed_after = (transition.value() - seam) / frame.as_secs_f32();
+
+        assert!(speed_after > 0.0);
+        assert!((speed_after - speed_before).abs() / speed_before < 0.01);
+        assert_eq!(*transition.target(), 2.0);
+    }
+
+    /// Retargeting on a flat part of a curve should keep the new duration within bounds.
+    #[test]
+    fn retarget_clamps_duration() {
+        let mut transition = Transition::new(0.0).to(1.0).with_easing(Easing::EASE_IN);
+        transition.advance(Duration::from_millis(3));
+        transition.retarget(2.0);
+        assert!(transition.duration() <= DEFAULT_DURATION * 4);
+        assert!(transition.duration() >= DEFAULT_MIN_DURATION);
+
+        // Nearly stationary values shouldn't panic when converting the duration.
+        let mut transition = Transition::new(0.0).to(1e-30).with_easing(Easing::EASE_IN);
+        transition.advance(Duration::from_nanos(1));
+        transition.retarget(1e30);
+        assert!(transition.duration() <= DEFAULT_DURATION * 4);
+    }
+
+    /// Retargeting should preserve the speed of the value, including its speed multiplier.
+    #[test]
+    fn retarget_with_speed() {
+        let mut transition = Transition::new(0.0)
+            .to(1.0)
+            .with_easing(Easing::LINEAR)
+            .with_speed(2.0);
+        transition.advance(DEFAULT_DURATION / 4);
+        assert_eq!(*transition.value(), 0.5);
+
+        // The value moves at 2 units per duration, so 1.5 units takes 3/4 of a duration.
+        transition.retarget(2.0);
+        transition.advance(DEFAULT_DURATION / 4);
+        assert!((transition.value() - 1.0).abs() < 1e-3);
+    }
+
+    /// Only the components that differ between the initial and target values should be changing.
+    #[test]
+    fn changing_components() {
+        let mut transition = Transition::new((0.0, 5.0));
+        assert_eq!(transition.changing_components(), vec![false, false]);
+
+        transition.set_target((1.0, 5.0));
+        assert_eq!(transition.changing_components(), vec![true, false]);
+
+        transition.settle_at((2.0, 2.0));
+        assert_eq!(transition.changing_components(), vec![false, false]);
+    }
+
+    /// Normalizing the speed should move the value along its path at the rate of the easing,
+    /// rather than letting the component that moves furthest dictate the speed.
+    #[test]
+    fn normalized_speed() {
+        let curves = vec![(0..1, Curve::EaseIn)];
+        let mut plain = Transition::new((0.0, 0.0))
+            .to((500.0, 0.1))
+            .with_easing(Easing::LINEAR)
+            .with_component_curves(curves.clone());
+        let mut normalized = plain.clone().with_normalized_speed(true);
+        let halfway = plain.last_update + DEFAULT_DURATION / 2;
+
+        plain.tick(halfway);
+        normalized.tick(halfway);
+
+        // The size dominates the length of the path, so normalizing moves it linearly.
+        let (plain_size, _) = *plain.value();
+        let (size, opacity) = *normalized.value();
+        assert!((plain_size - 500.0 * Curve::EaseIn.value(0.5)).abs() < 1e-3);
+        assert!((size - 250.0).abs() < 5.0);
+        assert!(opacity > 0.0 && opacity < 0.1);
+
+        let done = halfway + DEFAULT_DURATION;
+        plain.tick(done);
+        normalized.tick(done);
+        assert_eq!(*plain.value(), (500.0, 0.1));
+        assert_eq!(*normalized.value(), (500.0, 0.1));
+    }
+
+    /// Completed transitions should report which end they're resting at.
+    #[test]
+    fn resting_state() {
+        let mut transition = Transition::new(0.0)
+            .to(1.0)
+            .with_easing(Easing::LINEAR.reversible(true));
+        assert!(!transition.is_at_target() && !transition.is_at_initial());
+
+        transition.settle();
+        assert!(transition.is_at_target());
+        assert!(!transition.is_at_initial());
+
+        transition.set_progress_fraction(0.5);
+        transition.reverse();
+        transition.settle();
+        assert_eq!(*transition.value(), 0.0);
+        assert!(transition.is_at_initial());
+        assert!(!transition.is_at_target());
+    }
+
+    /// Transitions that only differ by floating-point noise should be approximately equal.
+    #[test]
+    fn approx_eq() {
+        let mut transition = Transition::new(0.0).to(1.0);
+        transition.set_progress_fraction(0.5);
+
+        let mut noisy = transition.clone();
+        noisy.value += 1e-7;
+        noisy.last_update += Duration::from_millis(1);
+        assert_ne!(transition, noisy);
+        assert!(transition.approx_eq(&noisy, 1e-6));
+
+        noisy.set_progress_fraction(0.6);
+        assert!(!transition.approx_eq(&noisy, 1e-6));
+    }
+
+    /// Subscriptions should only produce frames while the transition is animating.
+    #[test]
+    #[cfg(feature = "subscription")]
+    fn subscription() {
+        use iced_runtime::futures::subscription::into_recipes;
+
+        let mut transition = Transition::new(0.0);
+        assert!(into_recipes(transition.subscription()).is_empty());
+
+        transition.set_target(1.0);
+        assert_eq!(into_recipes(transition.subscription()).len(), 1);
+    }
+
+    /// [`Transition::set_target`] should report whether the target changed.
+    #[test]
+    fn set_target_returns_changed() {
+        let mut transition = Transition::new(0.0);
+        assert!(!transition.set_target(0.0));
+        assert!(transition.set_target(1.0));
+        assert!(!transition.set_target(1.0));
+        assert!(transition.is_animating());
+    }
 }
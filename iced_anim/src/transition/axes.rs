@@ -0,0 +1,136 @@
+//! Transitions that animate each axis of a point independently.
+use super::{Easing, Transition};
+use iced_core::Point;
+use std::time::{Duration, Instant};
+
+/// Animates a [`Point`] with a separate [`Transition`] for each axis, so the x and y values can
+/// use different durations and curves.
+///
+/// This is useful for gestures like drag-to-snap, where the horizontal position might snap
+/// quickly while the vertical position settles slowly. A `Transition<Point>` would force both
+/// axes to share the same timing.
+///
+/// ```rust
+/// # use iced_anim::transition::{Easing, Transition2D};
+/// # use iced_core::Point;
+/// let mut position = Transition2D::new(Point::ORIGIN)
+///     .with_x_easing(Easing::EASE_OUT)
+///     .with_y_easing(Easing::EASE.very_slow());
+/// position.set_target(Point::new(100.0, 50.0));
+/// assert!(position.is_animating());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Transition2D {
+    /// The transition of the horizontal position.
+    x: Transition<f32>,
+    /// The transition of the vertical position.
+    y: Transition<f32>,
+}
+
+impl Transition2D {
+    /// Creates a new [`Transition2D`] settled at the given `value`.
+    pub fn new(value: Point) -> Self {
+        Self {
+            x: Transition::new(value.x),
+            y: Transition::new(value.y),
+        }
+    }
+
+    /// Sets the easing of the horizontal transition and returns the updated transition.
+    pub fn with_x_easing(mut self, easing: Easing) -> Self {
+        self.x.set_easing(easing);
+        self
+    }
+
+    /// Sets the easing of the vertical transition and returns the updated transition.
+    pub fn with_y_easing(mut self, easing: Easing) -> Self {
+        self.y.set_easing(easing);
+        self
+    }
+
+    /// Returns the current value of both axes.
+    pub fn value(&self) -> Point {
+        Point::new(*self.x.value(), *self.y.value())
+    }
+
+    /// Returns the value that both axes are moving towards.
+    pub fn target(&self) -> Point {
+        Point::new(*self.x.target(), *self.y.target())
+    }
+
+    /// Returns a reference to the horizontal transition.
+    pub fn x(&self) -> &Transition<f32> {
+        &self.x
+    }
+
+    /// Returns a reference to the vertical transition.
+    pub fn y(&self) -> &Transition<f32> {
+        &self.y
+    }
+
+    /// Returns a mutable reference to the horizontal transition, e.g. to change its settings.
+    pub fn x_mut(&mut self) -> &mut Transition<f32> {
+        &mut self.x
+    }
+
+    /// Returns a mutable reference to the vertical transition, e.g. to change its settings.
+    pub fn y_mut(&mut self) -> &mut Transition<f32> {
+        &mut self.y
+    }
+
+    /// Animates each axis towards the matching coordinate of the `target`.
+    ///
+    /// Returns whether the target of either axis changed.
+    pub fn set_target(&mut self, target: Point) -> bool {
+        let x = self.x.set_target(target.x);
+        let y = self.y.set_target(target.y);
+        x || y
+    }
+
+    /// Updates both axes based on the elapsed time since their last update.
+    pub fn tick(&mut self, now: Instant) {
+        self.x.tick(now);
+        self.y.tick(now);
+    }
+
+    /// Advances both axes by the given amount of `elapsed` time.
+    pub fn advance(&mut self, elapsed: Duration) {
+        self.x.advance(elapsed);
+        self.y.advance(elapsed);
+    }
+
+    /// Ends both transitions, immediately moving each axis to its target.
+    pub fn settle(&mut self) {
+        self.x.settle();
+        self.y.settle();
+    }
+
+    /// Whether either axis is still animating towards its target.
+    pub fn is_animating(&self) -> bool {
+        self.x.is_animating() || self.y.is_animating()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Each axis should follow its own timing, and the point keeps animating until both finish.
+    #[test]
+    fn independent_axes() {
+        let mut position = Transition2D::new(Point::ORIGIN)
+            .with_x_easing(Easing::LINEAR.with_duration(Duration::from_millis(100)))
+            .with_y_easing(Easing::LINEAR.with_duration(Duration::from_millis(400)));
+        assert!(position.set_target(Point::new(1.0, 1.0)));
+        assert!(!position.set_target(Point::new(1.0, 1.0)));
+
+        position.advance(Duration::from_millis(100));
+        assert_eq!(position.value(), Point::new(1.0, 0.25));
+        assert!(!position.x().is_animating());
+        assert!(position.is_animating());
+
+        position.advance(Duration::from_millis(300));
+        assert_eq!(position.value(), position.target());
+        assert!(!position.is_animating());
+    }
+}
@@ -11,6 +11,10 @@
 //! - [Recreating CSS3 Cubic-Bezier Curves](https://stackoverflow.com/a/11697909)
 //! - [WebKit implementation](https://github.com/WebKit/WebKit/blob/main/Source/WebCore/platform/graphics/UnitBezier.h)
 //! - [Firefox implementation](https://github.com/mozilla/gecko-dev/blob/master/dom/smil/SMILKeySpline.cpp)
+//!
+//! Use [`evaluate`] to find points along bezier curves of any degree instead, e.g. to move a
+//! widget along a curved path.
+use iced_core::Point;
 use std::sync::LazyLock;
 
 /// Precision used for solving the curve.
@@ -102,6 +106,16 @@ impl Bezier {
         }
     }
 
+    /// The control points of the curve as `[x1, y1, x2, y2]`, matching [`Bezier::new`].
+    pub fn control_points(&self) -> [f32; 4] {
+        [
+            self.cx / 3.0,
+            self.cy / 3.0,
+            (self.bx + 2.0 * self.cx) / 3.0,
+            (self.by + 2.0 * self.cy) / 3.0,
+        ]
+    }
+
     fn sample_curve_x(ax: f32, bx: f32, cx: f32, t: f32) -> f32 {
         ((ax * t + bx) * t + cx) * t
     }
@@ -186,3 +200,98 @@ impl Bezier {
         }
     }
 }
+
+/// Evaluates the bezier curve with the given `control_points` at `t` using De Casteljau's
+/// algorithm, where `t` is in the range [0.0, 1.0].
+///
+/// The curve starts at the first control point and ends at the last one, so two points make a
+/// line, three make a quadratic curve, four make a cubic curve, and so on.
+///
+/// ```rust
+/// # use iced_core::Point;
+/// use iced_anim::transition::bezier;
+///
+/// let path = [Point::new(0.0, 0.0), Point::new(50.0, 100.0), Point::new(100.0, 0.0)];
+/// assert_eq!(bezier::evaluate(&path, 0.5), Point::new(50.0, 50.0));
+/// ```
+///
+/// # Panics
+///
+/// Panics if there are no `control_points`.
+pub fn evaluate(control_points: &[Point], t: f32) -> Point {
+    assert!(
+        !control_points.is_empty(),
+        "a bezier curve needs at least one control point"
+    );
+
+    // Repeatedly interpolate between neighboring points until only one is left.
+    let mut points = control_points.to_vec();
+    for len in (1..points.len()).rev() {
+        for i in 0..len {
+            let (start, end) = (points[i], points[i + 1]);
+            points[i] = Point::new(
+                start.x + (end.x - start.x) * t,
+                start.y + (end.y - start.y) * t,
+            );
+        }
+    }
+
+    points[0]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The control points should match the ones used to create the curve.
+    #[test]
+    fn control_points() {
+        let [x1, y1, x2, y2] = Bezier::new(0.25, 0.1, 0.25, 1.0).control_points();
+        assert!((x1 - 0.25).abs() < 1e-6);
+        assert!((y1 - 0.1).abs() < 1e-6);
+        assert!((x2 - 0.25).abs() < 1e-6);
+        assert!((y2 - 1.0).abs() < 1e-6);
+    }
+
+    /// Quadratic curves should pass through the expected points.
+    #[test]
+    fn evaluate_quadratic() {
+        let points = [
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 2.0),
+            Point::new(2.0, 0.0),
+        ];
+        assert_eq!(evaluate(&points, 0.0), points[0]);
+        assert_eq!(evaluate(&points, 0.5), Point::new(1.0, 1.0));
+        assert_eq!(evaluate(&points, 0.25), Point::new(0.5, 0.75));
+        assert_eq!(evaluate(&points, 1.0), points[2]);
+    }
+
+    /// Cubic curves should match the closed-form cubic bezier formula.
+    #[test]
+    fn evaluate_cubic() {
+        let points = [
+            Point::new(0.0, 0.0),
+            Point::new(0.0, 1.0),
+            Point::new(1.0, 1.0),
+            Point::new(1.0, 0.0),
+        ];
+        for t in [0.0, 0.2, 0.5, 0.9, 1.0] {
+            let u = 1.0 - t;
+            let weights = [u * u * u, 3.0 * u * u * t, 3.0 * u * t * t, t * t * t];
+            let x: f32 = weights.iter().zip(&points).map(|(w, p)| w * p.x).sum();
+            let y: f32 = weights.iter().zip(&points).map(|(w, p)| w * p.y).sum();
+
+            let point = evaluate(&points, t);
+            assert!((point.x - x).abs() < 1e-6);
+            assert!((point.y - y).abs() < 1e-6);
+        }
+    }
+
+    /// A single control point should always evaluate to that point.
+    #[test]
+    fn evaluate_single_point() {
+        let point = Point::new(3.0, 4.0);
+        assert_eq!(evaluate(&[point], 0.5), point);
+    }
+}
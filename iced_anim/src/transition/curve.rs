@@ -1,4 +1,11 @@
-use super::bezier::{Bezier, EASE, EASE_IN, EASE_IN_OUT, EASE_OUT};
+use super::{
+    bezier::{Bezier, EASE, EASE_IN, EASE_IN_OUT, EASE_OUT},
+    PiecewiseCurve,
+};
+use std::{f32::consts::PI, fmt::Write};
+
+/// The number of points sampled when tracing a curve as an SVG path.
+const SVG_PATH_SAMPLES: usize = 64;
 
 /// A curve that describes how a transition should progress.
 #[derive(Debug, Clone, Copy, Default)]
@@ -10,8 +17,34 @@ pub enum Curve {
     EaseIn,
     EaseOut,
     EaseInOut,
+    /// A curve that pulls back slightly past the start, then overshoots the end before
+    /// settling, following the standard "ease in out back" formula.
+    EaseInOutBack,
+    /// A curve that oscillates around the start and the end like a spring, following the
+    /// standard "ease in out elastic" formula.
+    EaseInOutElastic,
     /// A custom bezier curve.
     Bezier(Bezier),
+    /// A curve that jumps to the end as soon as the transition starts, which is useful for
+    /// discrete steps or disabling animation for a specific leg of a sequence.
+    Instant,
+    /// A curve that holds at the start until the very end of the transition, acting like a
+    /// delay for the duration of the transition.
+    Hold,
+    /// A curve that approximates a critically damped spring over a fixed duration, moving
+    /// quickly at first and then easing into the target with a long tail and no overshoot.
+    ///
+    /// Higher `tension` reaches the target sooner, with values around `5.0` to `15.0` working
+    /// well for most animations. Tensions below `1.0` are treated as `1.0`. This is only an
+    /// approximation of real spring physics, so use a [`Spring`](crate::Spring) to keep the
+    /// velocity across interruptions.
+    SpringLike {
+        /// How strongly the value is pulled towards the target.
+        tension: f32,
+    },
+    /// A curve made up of other curves over consecutive ranges of progress.
+    /// See [`PiecewiseCurve`] for more details.
+    Piecewise(PiecewiseCurve),
     /// A custom curve that takes a progress value in [0.0, 1.0] and returns the value to use
     /// for the transition when interpolating between two values. The output should generally be
     /// in the range of [0.0, 1.0].
@@ -23,18 +56,123 @@ impl Curve {
     ///
     /// Use this to interpolate between two values. The `progress` should be in the range of
     /// [0.0, 1.0] and represent the amount of time that has passed in the animation, where
-    /// 0.0 is the start and 1.0 is the end.
+    /// 0.0 is the start and 1.0 is the end. Progress outside of that range is clamped to it,
+    /// though curves like [`Curve::EaseInOutBack`] can still return values outside of it.
     pub fn value(&self, progress: f32) -> f32 {
+        let progress = progress.clamp(0.0, 1.0);
+
         match self {
             Curve::Linear => progress,
             Curve::Ease => EASE.solve(progress),
             Curve::EaseIn => EASE_IN.solve(progress),
             Curve::EaseOut => EASE_OUT.solve(progress),
             Curve::EaseInOut => EASE_IN_OUT.solve(progress),
+            Curve::EaseInOutBack => ease_in_out_back(progress),
+            Curve::EaseInOutElastic => ease_in_out_elastic(progress),
             Curve::Bezier(bezier) => bezier.solve(progress),
+            Curve::Instant => {
+                if progress > 0.0 {
+                    1.0
+                } else {
+                    0.0
+                }
+            }
+            Curve::Hold => {
+                if progress >= 1.0 {
+                    1.0
+                } else {
+                    0.0
+                }
+            }
+            Curve::SpringLike { tension } => spring_like(*tension, progress),
+            Curve::Piecewise(curve) => curve.value(progress),
             Curve::Custom(f) => f(progress),
         }
     }
+
+    /// Evaluates the curve at `count` evenly spaced progress values in [0.0, 1.0].
+    ///
+    /// This is useful for previewing the shape of a curve, e.g. in an easing editor.
+    pub fn sample_many(&self, count: usize) -> Vec<f32> {
+        let step = if count > 1 {
+            1.0 / (count - 1) as f32
+        } else {
+            0.0
+        };
+        (0..count).map(|i| self.value(i as f32 * step)).collect()
+    }
+
+    /// Traces the curve as SVG path data, which can be used as the `d` attribute of a `path`.
+    ///
+    /// The curve is scaled to fit a box of the given `width` and `height`, where progress moves
+    /// along the x-axis and the curve's value moves up the y-axis. Since the y-axis points down
+    /// in SVG coordinates, the path starts at the bottom-left and ends at the top-right of the
+    /// box for curves that start at 0.0 and end at 1.0.
+    pub fn to_svg_path(&self, width: f32, height: f32) -> String {
+        let step = 1.0 / (SVG_PATH_SAMPLES - 1) as f32;
+        let mut path = String::new();
+
+        for (i, value) in self.sample_many(SVG_PATH_SAMPLES).into_iter().enumerate() {
+            let command = if i == 0 { 'M' } else { 'L' };
+            let x = i as f32 * step * width;
+            let y = (1.0 - value) * height;
+            let separator = if i == 0 { "" } else { " " };
+            let _ = write!(path, "{separator}{command}{x:.2},{y:.2}");
+        }
+
+        path
+    }
+
+    /// Blends between two curves, where `t` is how far to move from curve `a` towards curve `b`,
+    /// e.g. to morph between two presets in an easing editor.
+    ///
+    /// Curves backed by a cubic bezier, including [`Curve::Linear`], are blended by
+    /// interpolating their control points. Other curves can't be blended since a
+    /// [`Curve::Custom`] can't capture both curves, so `a` is returned when `t` is less than
+    /// `0.5` and `b` is returned otherwise.
+    pub fn blend(a: &Curve, b: &Curve, t: f32) -> Curve {
+        if t <= 0.0 {
+            return *a;
+        } else if t >= 1.0 {
+            return *b;
+        }
+
+        match (a.control_points(), b.control_points()) {
+            (Some(a), Some(b)) => {
+                let [x1, y1, x2, y2] = std::array::from_fn(|i| a[i] + (b[i] - a[i]) * t);
+                Curve::Bezier(Bezier::new(x1, y1, x2, y2))
+            }
+            _ if t < 0.5 => *a,
+            _ => *b,
+        }
+    }
+
+    /// The cubic bezier control points that describe this curve, if any.
+    fn control_points(&self) -> Option<[f32; 4]> {
+        match self {
+            Curve::Linear => Some([0.0, 0.0, 1.0, 1.0]),
+            _ => self.bezier().map(Bezier::control_points),
+        }
+    }
+
+    /// The bezier curve backing this curve, if any.
+    fn bezier(&self) -> Option<&Bezier> {
+        match self {
+            Curve::Ease => Some(&EASE),
+            Curve::EaseIn => Some(&EASE_IN),
+            Curve::EaseOut => Some(&EASE_OUT),
+            Curve::EaseInOut => Some(&EASE_IN_OUT),
+            Curve::Bezier(bezier) => Some(bezier),
+            Curve::Linear
+            | Curve::EaseInOutBack
+            | Curve::EaseInOutElastic
+            | Curve::Instant
+            | Curve::Hold
+            | Curve::SpringLike { .. }
+            | Curve::Piecewise(_)
+            | Curve::Custom(_) => None,
+        }
+    }
 }
 
 impl PartialEq for Curve {
@@ -46,6 +184,12 @@ impl PartialEq for Curve {
             (Curve::EaseOut, Curve::EaseOut) => true,
             (Curve::EaseInOut, Curve::EaseInOut) => true,
             (Curve::Bezier(a), Curve::Bezier(b)) => a == b,
+            (Curve::EaseInOutBack, Curve::EaseInOutBack) => true,
+            (Curve::EaseInOutElastic, Curve::EaseInOutElastic) => true,
+            (Curve::Instant, Curve::Instant) => true,
+            (Curve::Hold, Curve::Hold) => true,
+            (Curve::SpringLike { tension: a }, Curve::SpringLike { tension: b }) => a == b,
+            (Curve::Piecewise(a), Curve::Piecewise(b)) => a == b,
             // This isn't a perfect comparison but should be good enough for most cases.
             // You might see issues if comparing custom curves across multiple codegen units
             (Curve::Custom(a), Curve::Custom(b)) => std::ptr::fn_addr_eq(*a, *b),
@@ -53,3 +197,181 @@ impl PartialEq for Curve {
         }
     }
 }
+
+/// The "ease in out back" curve, which overshoots both ends of the transition.
+/// See <https://easings.net/#easeInOutBack>.
+fn ease_in_out_back(progress: f32) -> f32 {
+    const OVERSHOOT: f32 = 1.70158 * 1.525;
+
+    if progress < 0.5 {
+        let t = 2.0 * progress;
+        t * t * ((OVERSHOOT + 1.0) * t - OVERSHOOT) / 2.0
+    } else {
+        let t = 2.0 * progress - 2.0;
+        (t * t * ((OVERSHOOT + 1.0) * t + OVERSHOOT) + 2.0) / 2.0
+    }
+}
+
+/// The "ease in out elastic" curve, which oscillates around both ends of the transition.
+/// See <https://easings.net/#easeInOutElastic>.
+fn ease_in_out_elastic(progress: f32) -> f32 {
+    const FREQUENCY: f32 = 2.0 * PI / 4.5;
+
+    if progress <= 0.0 {
+        0.0
+    } else if progress >= 1.0 {
+        1.0
+    } else if progress < 0.5 {
+        -(2f32.powf(20.0 * progress - 10.0) * ((20.0 * progress - 11.125) * FREQUENCY).sin()) / 2.0
+    } else {
+        2f32.powf(-20.0 * progress + 10.0) * ((20.0 * progress - 11.125) * FREQUENCY).sin() / 2.0
+            + 1.0
+    }
+}
+
+/// The position of a critically damped spring with the given `tension` at the `progress`,
+/// scaled so the curve reaches exactly 1.0 at the end of the transition.
+fn spring_like(tension: f32, progress: f32) -> f32 {
+    let tension = tension.max(1.0);
+    let position = |t: f32| 1.0 - (1.0 + tension * t) * (-tension * t).exp();
+
+    position(progress) / position(1.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Blending should match each curve at the ends and move between them in the middle.
+    #[test]
+    fn blend() {
+        for progress in [0.0, 0.25, 0.5, 0.75, 1.0] {
+            let start = Curve::blend(&Curve::Linear, &Curve::Ease, 0.0);
+            let end = Curve::blend(&Curve::Linear, &Curve::Ease, 1.0);
+            assert_eq!(start.value(progress), Curve::Linear.value(progress));
+            assert_eq!(end.value(progress), Curve::Ease.value(progress));
+        }
+
+        let middle = Curve::blend(&Curve::Linear, &Curve::Ease, 0.5).value(0.25);
+        assert!(middle > Curve::Linear.value(0.25));
+        assert!(middle < Curve::Ease.value(0.25));
+
+        // Curves that aren't bezier curves switch over halfway.
+        assert_eq!(Curve::blend(&Curve::Ease, &Curve::Hold, 0.4), Curve::Ease);
+        assert_eq!(Curve::blend(&Curve::Ease, &Curve::Hold, 0.6), Curve::Hold);
+    }
+
+    /// Sampling a linear curve should return evenly spaced values.
+    #[test]
+    fn sample_many_linear() {
+        let samples = Curve::Linear.sample_many(11);
+        assert_eq!(samples.len(), 11);
+        for (i, sample) in samples.iter().enumerate() {
+            assert!((sample - i as f32 / 10.0).abs() < 1e-6);
+        }
+    }
+
+    /// Sampling a bezier curve should match evaluating each value individually.
+    #[test]
+    fn sample_many_bezier() {
+        let samples = Curve::Ease.sample_many(5);
+        let expected: Vec<f32> = [0.0, 0.25, 0.5, 0.75, 1.0]
+            .iter()
+            .map(|t| Curve::Ease.value(*t))
+            .collect();
+        assert_eq!(samples, expected);
+    }
+
+    /// Spring-like curves should start and end at the targets, and higher tension should reach
+    /// the target faster without overshooting it.
+    #[test]
+    fn spring_like() {
+        let soft = Curve::SpringLike { tension: 4.0 };
+        let stiff = Curve::SpringLike { tension: 12.0 };
+
+        for curve in [soft, stiff] {
+            assert_eq!(curve.value(0.0), 0.0);
+            assert!((curve.value(1.0) - 1.0).abs() < 1e-6);
+            assert!(curve
+                .sample_many(32)
+                .iter()
+                .all(|value| *value <= 1.0 + 1e-6));
+        }
+
+        for progress in [0.1, 0.25, 0.5] {
+            assert!(stiff.value(progress) > soft.value(progress));
+        }
+    }
+
+    /// Progress outside of [0.0, 1.0] should be clamped before evaluating the curve.
+    #[test]
+    fn clamped_progress() {
+        for curve in [
+            Curve::Linear,
+            Curve::Ease,
+            Curve::EaseInOutBack,
+            Curve::EaseInOutElastic,
+            Curve::SpringLike { tension: 8.0 },
+            Curve::Custom(|t| t * t * t),
+        ] {
+            assert_eq!(curve.value(1.5), curve.value(1.0));
+            assert_eq!(curve.value(-0.5), curve.value(0.0));
+        }
+    }
+
+    /// Instant curves should jump to the end as soon as they start.
+    #[test]
+    fn instant() {
+        assert_eq!(Curve::Instant.value(0.0), 0.0);
+        assert_eq!(Curve::Instant.value(0.5), 1.0);
+        assert_eq!(Curve::Instant.value(1.0), 1.0);
+    }
+
+    /// Hold curves should stay at the start until the end.
+    #[test]
+    fn hold() {
+        assert_eq!(Curve::Hold.value(0.0), 0.0);
+        assert_eq!(Curve::Hold.value(0.5), 0.0);
+        assert_eq!(Curve::Hold.value(1.0), 1.0);
+    }
+
+    /// The in-out back curve should overshoot both ends and hit the endpoints exactly.
+    #[test]
+    fn ease_in_out_back() {
+        let curve = Curve::EaseInOutBack;
+        assert_eq!(curve.value(0.0), 0.0);
+        assert_eq!(curve.value(0.5), 0.5);
+        assert_eq!(curve.value(1.0), 1.0);
+        assert!((curve.value(0.25) + 0.099_681_84).abs() < 1e-5);
+        assert!((curve.value(0.75) - 1.099_681_8).abs() < 1e-5);
+    }
+
+    /// The in-out elastic curve should oscillate around both ends and hit the endpoints exactly.
+    #[test]
+    fn ease_in_out_elastic() {
+        let curve = Curve::EaseInOutElastic;
+        assert_eq!(curve.value(0.0), 0.0);
+        assert!((curve.value(0.5) - 0.5).abs() < 1e-6);
+        assert_eq!(curve.value(1.0), 1.0);
+        assert!((curve.value(0.4) + 0.117_461_58).abs() < 1e-5);
+        assert!((curve.value(0.75) - 0.988_030_6).abs() < 1e-5);
+    }
+
+    /// Sampling no values should be empty, and a single sample should be the start.
+    #[test]
+    fn sample_many_edge_counts() {
+        assert!(Curve::Linear.sample_many(0).is_empty());
+        assert_eq!(Curve::Linear.sample_many(1), vec![0.0]);
+    }
+
+    /// SVG paths should go from the bottom-left to the top-right for monotonic curves.
+    #[test]
+    fn to_svg_path() {
+        let path = Curve::EaseInOut.to_svg_path(200.0, 100.0);
+        let points: Vec<&str> = path.split(' ').collect();
+        assert_eq!(points.len(), SVG_PATH_SAMPLES);
+        assert_eq!(points[0], "M0.00,100.00");
+        assert_eq!(points[points.len() - 1], "L200.00,0.00");
+        assert!(points[1..].iter().all(|point| point.starts_with('L')));
+    }
+}
@@ -0,0 +1,149 @@
+//! A transition that animates towards a value derived from some state.
+use super::{Easing, Transition};
+use crate::Animate;
+use std::time::Instant;
+
+/// A [`Transition`] whose target is a projection of some `state`.
+///
+/// This is useful when your model stores a value that isn't animatable itself, like an enum,
+/// but maps to an animatable value. Changing the state with [`MappedTransition::set_state`]
+/// automatically moves the transition towards the mapped target.
+///
+/// ```rust
+/// # use iced_anim::transition::MappedTransition;
+/// #[derive(Debug, Clone, Copy, PartialEq)]
+/// enum Panel {
+///     Closed,
+///     Open,
+/// }
+///
+/// let mut width = MappedTransition::new(Panel::Closed, |panel| match panel {
+///     Panel::Closed => 0.0,
+///     Panel::Open => 200.0,
+/// });
+/// width.set_state(Panel::Open);
+/// assert_eq!(width.target(), &200.0);
+/// ```
+#[derive(Debug, Clone)]
+pub struct MappedTransition<S, T> {
+    /// The current state that the target is derived from.
+    state: S,
+    /// Maps the state to the target value of the transition.
+    map: fn(&S) -> T,
+    /// The transition animating towards the mapped target.
+    transition: Transition<T>,
+}
+
+impl<S, T> MappedTransition<S, T>
+where
+    S: PartialEq,
+    T: Animate,
+{
+    /// Creates a new [`MappedTransition`] settled at the value mapped from the given `state`.
+    pub fn new(state: S, map: fn(&S) -> T) -> Self {
+        let transition = Transition::new(map(&state));
+        Self {
+            state,
+            map,
+            transition,
+        }
+    }
+
+    /// Sets the easing to use for the transition and returns the updated transition.
+    pub fn with_easing(mut self, easing: Easing) -> Self {
+        self.transition.set_easing(easing);
+        self
+    }
+
+    /// Returns a reference to the current state.
+    pub fn state(&self) -> &S {
+        &self.state
+    }
+
+    /// Updates the state, animating towards the newly mapped target if the state changed.
+    pub fn set_state(&mut self, state: S) {
+        if self.state == state {
+            return;
+        }
+
+        self.transition.set_target((self.map)(&state));
+        self.state = state;
+    }
+
+    /// Returns a reference to the current interpolated value.
+    pub fn value(&self) -> &T {
+        self.transition.value()
+    }
+
+    /// Returns a reference to the value that the transition is moving towards.
+    pub fn target(&self) -> &T {
+        self.transition.target()
+    }
+
+    /// Returns a reference to the underlying transition.
+    pub fn transition(&self) -> &Transition<T> {
+        &self.transition
+    }
+
+    /// Updates the interpolated value based on the elapsed time since the last update.
+    pub fn tick(&mut self, now: Instant) {
+        self.transition.tick(now);
+    }
+
+    /// Whether the transition is currently animating towards its target.
+    pub fn is_animating(&self) -> bool {
+        self.transition.is_animating()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::animated::DEFAULT_DURATION;
+    use std::time::Duration;
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum Status {
+        Idle,
+        Loading,
+        Done,
+    }
+
+    fn progress_of(status: &Status) -> f32 {
+        match status {
+            Status::Idle => 0.0,
+            Status::Loading => 0.5,
+            Status::Done => 1.0,
+        }
+    }
+
+    /// Changing the state should animate towards the mapped target.
+    #[test]
+    fn maps_state_to_target() {
+        let mut transition = MappedTransition::new(Status::Idle, progress_of);
+        assert_eq!(*transition.value(), 0.0);
+        assert!(!transition.is_animating());
+
+        transition.set_state(Status::Loading);
+        assert_eq!(*transition.target(), 0.5);
+        assert!(transition.is_animating());
+
+        let done = Instant::now() + DEFAULT_DURATION + Duration::from_millis(1);
+        transition.tick(done);
+        assert_eq!(*transition.value(), 0.5);
+
+        transition.set_state(Status::Done);
+        assert_eq!(*transition.target(), 1.0);
+        transition.tick(done + DEFAULT_DURATION + Duration::from_millis(1));
+        assert_eq!(*transition.value(), 1.0);
+        assert_eq!(*transition.state(), Status::Done);
+    }
+
+    /// Setting the same state shouldn't restart the transition.
+    #[test]
+    fn unchanged_state_does_nothing() {
+        let mut transition = MappedTransition::new(Status::Done, progress_of);
+        transition.set_state(Status::Done);
+        assert!(!transition.is_animating());
+    }
+}
@@ -0,0 +1,128 @@
+//! Curves made up of other curves over different ranges of progress.
+use super::Curve;
+
+/// A curve that splices together other [`Curve`]s over consecutive ranges of progress.
+///
+/// Each segment is an `(end, curve)` pair, where `end` is the progress at which the segment
+/// ends and the next one begins. The first segment starts at `0.0`. Within a segment, progress
+/// is remapped to the range [0.0, 1.0] before applying the segment's curve, and the result is
+/// scaled back to the segment's range so that the overall curve is continuous.
+///
+/// The segments are borrowed for the `'static` lifetime, e.g. from a slice literal, so that the
+/// curve can be copied into a [`Curve::Piecewise`] and used by any transition.
+///
+/// ```rust
+/// # use iced_anim::transition::{Curve, PiecewiseCurve};
+/// # use iced_anim::{Easing, Transition};
+/// // Ease in over the first 40% of the transition, then move linearly.
+/// let curve = PiecewiseCurve::new(&[(0.4, Curve::EaseIn), (1.0, Curve::Linear)]);
+/// assert_eq!(curve.value(0.4), 0.4);
+/// assert_eq!(curve.value(0.7), 0.7);
+///
+/// let transition = Transition::new(0.0).with_easing(Easing::new(Curve::Piecewise(curve)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PiecewiseCurve {
+    /// The end of each segment's range of progress and the curve used within it.
+    segments: &'static [(f32, Curve)],
+}
+
+impl PiecewiseCurve {
+    /// Creates a new [`PiecewiseCurve`] from `(end, curve)` segments.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are no segments, if the segment ends aren't strictly increasing within
+    /// (0.0, 1.0], or if the last segment doesn't end at `1.0`.
+    pub fn new(segments: &'static [(f32, Curve)]) -> Self {
+        assert!(
+            !segments.is_empty(),
+            "a piecewise curve needs at least one segment"
+        );
+
+        let mut start = 0.0;
+        for (end, _) in segments {
+            assert!(
+                *end > start && *end <= 1.0,
+                "piecewise curve segments must be sorted and within (0.0, 1.0], found {end} after {start}"
+            );
+            start = *end;
+        }
+        assert!(
+            start == 1.0,
+            "the last segment of a piecewise curve must end at 1.0, found {start}"
+        );
+
+        Self { segments }
+    }
+
+    /// The segments of the curve as `(end, curve)` pairs.
+    pub fn segments(&self) -> &'static [(f32, Curve)] {
+        self.segments
+    }
+
+    /// The value of the curve at the given `progress`.
+    /// See [`Curve::value`] for more details.
+    pub fn value(&self, progress: f32) -> f32 {
+        let last = self.segments.len() - 1;
+        let mut start = 0.0;
+        for (index, (end, curve)) in self.segments.iter().enumerate() {
+            // Progress past the end of the curve follows the last segment.
+            if progress <= *end || index == last {
+                let local = (progress - start) / (end - start);
+                return start + (end - start) * curve.value(local);
+            }
+            start = *end;
+        }
+
+        unreachable!("piecewise curves always have at least one segment")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Each segment should follow its own curve and meet the next at the boundary.
+    #[test]
+    fn two_segments() {
+        let curve = PiecewiseCurve::new(&[(0.4, Curve::EaseIn), (1.0, Curve::Linear)]);
+
+        assert_eq!(curve.value(0.0), 0.0);
+        assert!((curve.value(0.2) - 0.4 * Curve::EaseIn.value(0.5)).abs() < 1e-6);
+        assert!((curve.value(0.4) - 0.4).abs() < 1e-6);
+        assert!((curve.value(0.4 + 1e-4) - 0.4).abs() < 1e-3);
+        assert!((curve.value(0.7) - 0.7).abs() < 1e-6);
+        assert_eq!(curve.value(1.0), 1.0);
+    }
+
+    /// Piecewise curves should drive a transition like any other curve.
+    #[test]
+    fn transition() {
+        use crate::{animated::DEFAULT_DURATION, Easing, Transition};
+
+        let curve = PiecewiseCurve::new(&[(0.5, Curve::Hold), (1.0, Curve::Linear)]);
+        let mut transition = Transition::new(0.0)
+            .to(1.0)
+            .with_easing(Easing::new(Curve::Piecewise(curve)));
+
+        transition.advance(DEFAULT_DURATION / 4);
+        assert_eq!(*transition.value(), 0.0);
+        transition.advance(DEFAULT_DURATION / 2);
+        assert!((transition.value() - 0.75).abs() < 1e-6);
+    }
+
+    /// Segments that aren't sorted should be rejected.
+    #[test]
+    #[should_panic]
+    fn unsorted_segments() {
+        PiecewiseCurve::new(&[(0.6, Curve::Linear), (0.4, Curve::Linear)]);
+    }
+
+    /// Segments that don't cover the whole range should be rejected.
+    #[test]
+    #[should_panic]
+    fn incomplete_segments() {
+        PiecewiseCurve::new(&[(0.5, Curve::Linear)]);
+    }
+}
@@ -9,6 +9,15 @@ pub enum Progress {
     Reverse(f32),
 }
 
+/// The direction that a [`Progress`] is moving in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    /// Moving from the initial value towards the target.
+    Forward,
+    /// Moving from the target back towards the initial value.
+    Reverse,
+}
+
 impl Default for Progress {
     fn default() -> Self {
         Self::Forward(1.0)
@@ -38,6 +47,20 @@ impl Progress {
         }
     }
 
+    /// Gets how much progress is left to complete the transition in its current direction,
+    /// e.g. a [`Progress::Reverse`] at 0.3 on the curve has 0.3 left to reach the initial end.
+    pub fn remaining(&self) -> f32 {
+        1.0 - self.progress()
+    }
+
+    /// Gets the [`Direction`] that the progress is moving in.
+    pub fn direction(&self) -> Direction {
+        match self {
+            Self::Forward(_) => Direction::Forward,
+            Self::Reverse(_) => Direction::Reverse,
+        }
+    }
+
     /// Reverses this status, e.g. Idle -> Idle, Forward -> Reverse, Reverse -> Forward.
     /// Reversing will swap the progress value, e.g. Forward(0.2) -> Reverse(0.8).
     pub fn reverse(&mut self) {
@@ -149,4 +172,18 @@ mod tests {
     fn default() {
         assert_eq!(Progress::default(), Progress::Forward(1.0));
     }
+
+    /// [`Progress::remaining`] should be the progress left in the current direction.
+    #[test]
+    fn remaining() {
+        let forward = Progress::Forward(0.3);
+        assert_eq!(forward.direction(), Direction::Forward);
+        assert!((forward.remaining() - 0.7).abs() < 1e-6);
+
+        // Reversing at 0.3 on the curve leaves 0.3 to get back to the initial end.
+        let reverse = Progress::Reverse(0.7);
+        assert_eq!(reverse.value(), 0.3);
+        assert_eq!(reverse.direction(), Direction::Reverse);
+        assert!((reverse.remaining() - 0.3).abs() < 1e-6);
+    }
 }
@@ -0,0 +1,144 @@
+//! A sequence of transitions that play one after another.
+use super::{Easing, Transition};
+use crate::Animate;
+use std::{
+    collections::VecDeque,
+    time::{Duration, Instant},
+};
+
+/// A [`Transition`] that moves through a series of keyframes, one after another.
+///
+/// Each keyframe is a target value and the [`Easing`] used to reach it. Sequences are usually
+/// built by chaining [`Transition::then`]:
+///
+/// ```rust
+/// # use iced_anim::transition::{Easing, Transition};
+/// # use std::time::Duration;
+/// let mut sequence = Transition::new(0.0)
+///     .then(1.0, Easing::EASE)
+///     .then(0.5, Easing::LINEAR.quick());
+///
+/// sequence.advance(Duration::from_millis(500));
+/// assert_eq!(*sequence.value(), 1.0);
+///
+/// sequence.advance(Duration::from_millis(200));
+/// assert_eq!(*sequence.value(), 0.5);
+/// assert!(!sequence.is_animating());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Sequence<T> {
+    /// The transition towards the current keyframe.
+    transition: Transition<T>,
+    /// The keyframes that haven't started yet.
+    keyframes: VecDeque<(T, Easing)>,
+    /// The time at which the sequence was last updated.
+    last_update: Instant,
+}
+
+impl<T> Sequence<T>
+where
+    T: Animate,
+{
+    /// Creates a new [`Sequence`] that plays its keyframes after the given `transition` ends.
+    pub fn new(transition: Transition<T>) -> Self {
+        Self {
+            transition,
+            keyframes: VecDeque::new(),
+            last_update: Instant::now(),
+        }
+    }
+
+    /// Appends a keyframe that moves to the `target` with the given `easing` once the previous
+    /// keyframes are done, and returns the updated sequence.
+    pub fn then(mut self, target: T, easing: Easing) -> Self {
+        self.keyframes.push_back((target, easing));
+        self
+    }
+
+    /// Returns a reference to the current value of the sequence.
+    pub fn value(&self) -> &T {
+        self.transition.value()
+    }
+
+    /// Returns a reference to the final value of the sequence, i.e. the target of its
+    /// last keyframe.
+    pub fn target(&self) -> &T {
+        self.keyframes
+            .back()
+            .map_or_else(|| self.transition.target(), |(target, _)| target)
+    }
+
+    /// Returns a reference to the transition towards the current keyframe.
+    pub fn transition(&self) -> &Transition<T> {
+        &self.transition
+    }
+
+    /// Whether the sequence is still moving through its keyframes.
+    pub fn is_animating(&self) -> bool {
+        self.transition.is_animating() || !self.keyframes.is_empty()
+    }
+
+    /// Updates the sequence based on the elapsed time since the last update.
+    pub fn tick(&mut self, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.last_update);
+        self.last_update = now;
+        self.advance(elapsed);
+    }
+
+    /// Moves the sequence forward by the given `elapsed` time, starting the next keyframes
+    /// as the previous ones end.
+    pub fn advance(&mut self, mut elapsed: Duration) {
+        loop {
+            if !self.transition.is_animating() {
+                let Some((target, easing)) = self.keyframes.pop_front() else {
+                    return;
+                };
+                self.transition.set_easing(easing);
+                self.transition.set_target(target);
+                continue;
+            }
+
+            // Carry any time left over from this keyframe into the next one.
+            let remaining = self.transition.remaining();
+            if elapsed < remaining {
+                self.transition.advance(elapsed);
+                return;
+            }
+
+            self.transition.settle();
+            elapsed -= remaining;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::animated::DEFAULT_DURATION;
+
+    /// Sequences should visit each keyframe in order.
+    #[test]
+    fn visits_keyframes() {
+        let mut sequence = Transition::new(0.0)
+            .then(1.0, Easing::EASE)
+            .then(0.5, Easing::LINEAR.quick());
+        assert_eq!(*sequence.target(), 0.5);
+        let start = sequence.last_update;
+
+        sequence.tick(start + DEFAULT_DURATION / 2);
+        assert!(*sequence.value() > 0.0 && *sequence.value() < 1.0);
+        assert_eq!(*sequence.transition().target(), 1.0);
+
+        sequence.tick(start + DEFAULT_DURATION);
+        assert_eq!(*sequence.value(), 1.0);
+        assert!(sequence.is_animating());
+
+        // Time past the end of a keyframe should carry into the next one.
+        sequence.tick(start + DEFAULT_DURATION + Duration::from_millis(100));
+        assert!((sequence.value() - 0.75).abs() < 1e-4);
+
+        sequence.tick(start + DEFAULT_DURATION * 2);
+        assert_eq!(*sequence.value(), 0.5);
+        assert!(!sequence.is_animating());
+    }
+}
@@ -0,0 +1,104 @@
+//! Animatable window positions that snap to whole pixels.
+//!
+//! Windows can't be placed at fractional positions on most platforms, so moving a window with
+//! an animated [`Point`] can make it jitter as each frame is rounded differently. A
+//! [`WindowPos`] animates continuously but always reads back as whole pixels, e.g.
+//!
+//! ```rust
+//! # use iced_core::Point;
+//! use iced_anim::{window::WindowPos, Animate};
+//!
+//! let start = WindowPos::new(0.0, 0.0);
+//! let end = WindowPos::new(100.0, 25.0);
+//!
+//! let mut position = start;
+//! position.lerp(&start, &end, 0.5);
+//! assert_eq!(position.position(), Point::new(50.0, 13.0));
+//! ```
+use crate::Animate;
+use iced_core::{window, Point};
+
+/// The position of a window that animates smoothly but reads back as whole pixels.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct WindowPos {
+    /// The exact, unrounded position of the window.
+    exact: Point,
+}
+
+impl WindowPos {
+    /// Creates a new [`WindowPos`] at the given `x` and `y` coordinates.
+    pub fn new(x: f32, y: f32) -> Self {
+        Self {
+            exact: Point::new(x, y),
+        }
+    }
+
+    /// The position of the window, rounded to whole pixels.
+    pub fn position(&self) -> Point {
+        Point::new(self.exact.x.round(), self.exact.y.round())
+    }
+
+    /// The exact position of the window, which may be between pixels while animating.
+    pub fn exact(&self) -> Point {
+        self.exact
+    }
+}
+
+impl From<Point> for WindowPos {
+    fn from(exact: Point) -> Self {
+        Self { exact }
+    }
+}
+
+impl From<WindowPos> for Point {
+    fn from(position: WindowPos) -> Self {
+        position.position()
+    }
+}
+
+impl From<WindowPos> for window::Position {
+    fn from(position: WindowPos) -> Self {
+        window::Position::Specific(position.position())
+    }
+}
+
+impl Animate for WindowPos {
+    fn components() -> usize {
+        Point::<f32>::components()
+    }
+
+    fn update(&mut self, components: &mut impl Iterator<Item = f32>) {
+        self.exact.update(components);
+    }
+
+    fn distance_to(&self, end: &Self) -> Vec<f32> {
+        self.exact.distance_to(&end.exact)
+    }
+
+    fn lerp(&mut self, start: &Self, end: &Self, progress: f32) {
+        self.exact.lerp(&start.exact, &end.exact, progress);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Positions should always read back as whole pixels while animating continuously.
+    #[test]
+    fn rounds_to_whole_pixels() {
+        let start = WindowPos::new(0.0, 0.0);
+        let end = WindowPos::new(10.0, 3.0);
+        let mut position = start;
+
+        for step in 0..=20 {
+            let progress = step as f32 / 20.0;
+            position.lerp(&start, &end, progress);
+
+            let rounded = position.position();
+            assert_eq!(rounded.x.fract(), 0.0);
+            assert_eq!(rounded.y.fract(), 0.0);
+            assert!((position.exact().x - 10.0 * progress).abs() < 1e-5);
+        }
+    }
+}
@@ -0,0 +1,115 @@
+//! Drives an `AnimationBuilder` headlessly with synthetic times instead of window redraws.
+use iced_anim::{transition::Easing, AnimationBuilder};
+use iced_core::{
+    clipboard,
+    layout::{self, Limits},
+    mouse, renderer,
+    widget::{Tree, Widget},
+    window, Element, Event, Layout, Length, Rectangle, Shell, Size, Theme,
+};
+use std::{
+    cell::Cell,
+    rc::Rc,
+    time::{Duration, Instant},
+};
+
+/// A widget without content, since the animated value is observed through the builder.
+struct Empty;
+
+impl<Message> Widget<Message, Theme, ()> for Empty {
+    fn size(&self) -> Size<Length> {
+        Size::new(Length::Shrink, Length::Shrink)
+    }
+
+    fn layout(&mut self, _tree: &mut Tree, _renderer: &(), _limits: &Limits) -> layout::Node {
+        layout::Node::new(Size::ZERO)
+    }
+
+    fn draw(
+        &self,
+        _tree: &Tree,
+        _renderer: &mut (),
+        _theme: &Theme,
+        _style: &renderer::Style,
+        _layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+    }
+}
+
+/// Builds, lays out and redraws a frame of an animated `target` at the time `now`, returning
+/// the values published by `on_interpolate`.
+fn frame(tree: &mut Option<Tree>, built: &Rc<Cell<f32>>, target: f32, now: Instant) -> Vec<f32> {
+    let built = built.clone();
+    let mut element: Element<'_, f32, Theme, ()> = AnimationBuilder::new(target, move |value| {
+        built.set(value);
+        Element::new(Empty)
+    })
+    .animation(Easing::LINEAR.with_duration(Duration::from_millis(100)))
+    .on_interpolate(|value| value)
+    .clock(now)
+    .into();
+
+    let tree = tree.get_or_insert_with(|| Tree::new(&element));
+    tree.diff(&element);
+    let node = element
+        .as_widget_mut()
+        .layout(tree, &(), &Limits::new(Size::ZERO, Size::INFINITE));
+
+    let mut messages = Vec::new();
+    element.as_widget_mut().update(
+        tree,
+        &Event::Window(window::Event::RedrawRequested(now)),
+        Layout::new(&node),
+        mouse::Cursor::Unavailable,
+        &(),
+        &mut clipboard::Null,
+        &mut Shell::new(&mut messages),
+        &Rectangle::with_size(Size::INFINITE),
+    );
+    messages
+}
+
+/// The animation should advance by the time between the clocks of consecutive frames.
+#[test]
+fn synthetic_clock() {
+    let start = Instant::now();
+    let built = Rc::new(Cell::new(f32::NAN));
+    let mut tree = None;
+
+    assert!(frame(&mut tree, &built, 0.0, start).is_empty());
+    assert_eq!(built.get(), 0.0);
+
+    // Changing the target after being idle starts the animation from the current frame.
+    assert_eq!(
+        frame(&mut tree, &built, 1.0, start + Duration::from_millis(50)),
+        [0.0]
+    );
+    assert_eq!(built.get(), 0.0);
+
+    assert_eq!(
+        frame(&mut tree, &built, 1.0, start + Duration::from_millis(75)),
+        [0.25]
+    );
+    assert_eq!(built.get(), 0.25);
+
+    assert_eq!(
+        frame(&mut tree, &built, 1.0, start + Duration::from_secs(1)),
+        [1.0]
+    );
+    assert_eq!(built.get(), 1.0);
+
+    // Idle frames don't advance or publish anything.
+    assert!(frame(&mut tree, &built, 1.0, start + Duration::from_secs(2)).is_empty());
+
+    // Animating again after being idle doesn't skip ahead by the idle time.
+    assert_eq!(
+        frame(&mut tree, &built, 0.0, start + Duration::from_secs(5)),
+        [1.0]
+    );
+    assert_eq!(
+        frame(&mut tree, &built, 0.0, start + Duration::from_millis(5050)),
+        [0.5]
+    );
+}
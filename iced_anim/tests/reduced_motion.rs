@@ -0,0 +1,30 @@
+//! Reduced motion is global, so these tests run in their own process
+//! to avoid affecting other animations under test.
+use iced_anim::{transition::Easing, Spring, Transition};
+use std::time::{Duration, Instant};
+
+/// Enabling reduced motion should make animations settle in a single tick.
+#[test]
+fn reduced_motion_settles_in_one_tick() {
+    let mut transition = Transition::new(0.0)
+        .to(1.0)
+        .with_easing(Easing::LINEAR.with_duration(Duration::from_millis(500)));
+    let mut spring = Spring::new(0.0).to(1.0);
+
+    iced_anim::set_reduced_motion(true);
+    assert!(iced_anim::is_reduced_motion());
+
+    let now = Instant::now() + Duration::from_millis(16);
+    transition.tick(now);
+    spring.tick(now);
+    assert_eq!(*transition.value(), 1.0);
+    assert!(!transition.is_animating());
+    assert_eq!(*spring.value(), 1.0);
+    assert!(!spring.has_energy());
+
+    // Animations should animate normally once reduced motion is disabled again.
+    iced_anim::set_reduced_motion(false);
+    transition.set_target(0.0);
+    transition.tick(Instant::now() + Duration::from_millis(250));
+    assert!(transition.is_animating());
+}
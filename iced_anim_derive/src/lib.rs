@@ -58,6 +58,13 @@ pub fn animate_derive(input: TokenStream) -> TokenStream {
         }
     });
 
+    let can_animate_fields = fields.named.iter().map(|f| {
+        let name = &f.ident;
+        quote! {
+            && ::iced_anim::Animate::can_animate_to(&self.#name, &end.#name)
+        }
+    });
+
     let impl_gen = quote! {
         impl ::iced_anim::Animate for #name {
             fn components() -> usize {
@@ -79,6 +86,10 @@ pub fn animate_derive(input: TokenStream) -> TokenStream {
             fn lerp(&mut self, start: &Self, end: &Self, progress: ::core::primitive::f32) {
                 #(#lerp_fields)*
             }
+
+            fn can_animate_to(&self, end: &Self) -> bool {
+                true #(#can_animate_fields)*
+            }
         }
     };
 